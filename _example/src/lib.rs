@@ -1,11 +1,6 @@
 use {
     futures_signals::signal::Mutable,
-    rustsx::{
-        dominator,
-        prelude::{events::KeyUp, *},
-        use_head,
-    },
-    web_sys::HtmlInputElement,
+    rustsx::{dominator, prelude::*, use_head},
 };
 
 #[component]
@@ -58,7 +53,7 @@ fn demo() -> dominator::Dom {
                     <input
                         class="w-full px-6 py-4 text-lg border-2 border-purple-300 rounded-lg focus:outline-none focus:border-purple-500 focus:ring-4 focus:ring-purple-200 transition-all duration-200"
                         placeholder="Type your name here..."
-                        onkeyup={|e: KeyUp| name.set(e.dyn_target::<HtmlInputElement>().unwrap().value())}
+                        bind:value={name}
                     />
                 </div>
 
@@ -0,0 +1,37 @@
+use std::{env, fs, path::Path};
+
+// Lists the `StaticEvent` types in `src/events.rs` along with their event names, so `rsx!` can map `onclick` to
+// `events::Click` without keeping its own copy of the list
+fn main() {
+    println!("cargo:rerun-if-changed=src/events.rs");
+
+    let source = fs::read_to_string("src/events.rs").expect("Failed to read src/events.rs");
+    let mut events = Vec::new();
+    let mut lines = source.lines().map(str::trim);
+
+    while let Some(line) = lines.next() {
+        // static_event_impl!(Click => "click");
+        if let Some(args) = line.strip_prefix("static_event_impl!(").and_then(|line| line.strip_suffix(");")) {
+            let (name, event) = args.split_once("=>").expect("Malformed static_event_impl!");
+            events.push((event.trim().trim_matches('"').to_owned(), name.trim().to_owned()));
+        }
+        // impl StaticEvent for MouseEnter { const EVENT_TYPE: &'static str = "mouseenter"; .. }
+        else if let Some(name) = line
+            .strip_prefix("impl StaticEvent for ")
+            .and_then(|line| line.strip_suffix(" {"))
+            .filter(|name| !name.starts_with('$'))
+        {
+            let event = lines
+                .by_ref()
+                .find_map(|line| line.strip_prefix("const EVENT_TYPE: &'static str = "))
+                .expect("StaticEvent impl without an EVENT_TYPE");
+            events.push((event.trim_end_matches(';').trim_matches('"').to_owned(), name.to_owned()));
+        }
+    }
+
+    events.sort();
+    let entries = events.iter().map(|(event, name)| format!("    (\"{event}\", \"{name}\"),\n")).collect::<String>();
+
+    let dest = Path::new(&env::var("OUT_DIR").unwrap()).join("static_events.rs");
+    fs::write(dest, format!("&[\n{entries}]\n")).expect("Failed to write static_events.rs");
+}
@@ -5,7 +5,7 @@ use {
         fragment::{Fragment, FragmentBuilder},
         operations::{self, for_each, spawn_future},
         traits::*,
-        utils::{EventListener, FnDiscard, MutableListener, RefCounter, UnwrapJsExt, ValueDiscard, on},
+        utils::{EventListener, FnDiscard, MutableListener, RefCounter, UnwrapJsExt, ValueDiscard, on, on_custom},
     },
     discard::{Discard, DiscardOnDrop},
     futures_channel::oneshot,
@@ -745,15 +745,17 @@ where
 pub struct EventOptions {
     pub bubbles: bool,
     pub preventable: bool,
+    /// Remove the listener after it first runs
+    pub once: bool,
 }
 
 impl EventOptions {
     pub fn bubbles() -> Self {
-        Self { bubbles: true, preventable: false }
+        Self { bubbles: true, ..Self::default() }
     }
 
     pub fn preventable() -> Self {
-        Self { bubbles: false, preventable: true }
+        Self { preventable: true, ..Self::default() }
     }
 
     pub(crate) fn into_gloo(self) -> gloo_events::EventListenerOptions {
@@ -885,6 +887,15 @@ where
     }
 }
 
+impl<A> AsRef<Element> for DomBuilder<A>
+where
+    A: AsRef<Element>,
+{
+    fn as_ref(&self) -> &Element {
+        self.element.as_ref()
+    }
+}
+
 impl<A> DomBuilder<A>
 where
     A: JsCast,
@@ -1162,6 +1173,27 @@ where
     {
         self.event_with_options(&T::default_options(true), listener)
     }
+
+    /// Like [`event_with_options`](Self::event_with_options), for events without a [`StaticEvent`] type such as
+    /// custom events. The name is the event's type, e.g. `"my-event"`.
+    #[inline]
+    #[track_caller]
+    pub fn custom_event_with_options<F>(mut self, name: &str, options: &EventOptions, listener: F) -> Self
+    where
+        F: FnMut(crate::events::Custom) + 'static,
+    {
+        self.callbacks.after_remove(on_custom(self.element.as_ref(), name, options, listener));
+        self
+    }
+
+    #[inline]
+    #[track_caller]
+    pub fn custom_event<F>(self, name: &str, listener: F) -> Self
+    where
+        F: FnMut(crate::events::Custom) + 'static,
+    {
+        self.custom_event_with_options(name, &EventOptions::bubbles(), listener)
+    }
 }
 
 impl<A> DomBuilder<A>
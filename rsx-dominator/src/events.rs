@@ -95,6 +95,11 @@ macro_rules! make_event {
                 self.event.target()
             }
 
+            #[inline]
+            pub fn current_target(&self) -> Option<EventTarget> {
+                self.event.current_target()
+            }
+
             #[inline]
             pub fn dyn_target<A>(&self) -> Option<A>
             where
@@ -265,6 +270,18 @@ macro_rules! make_animation_event {
     };
 }
 
+macro_rules! make_transition_event {
+    ($name:ident) => {
+        make_event!($name => web_sys::TransitionEvent);
+
+        impl $name {
+            #[inline] pub fn property_name(&self) -> String { self.event.property_name() }
+            #[inline] pub fn elapsed_time(&self) -> f32 { self.event.elapsed_time() }
+            #[inline] pub fn pseudo_element(&self) -> String { self.event.pseudo_element() }
+        }
+    };
+}
+
 macro_rules! make_wheel_event {
     ($name:ident) => {
         make_mouse_event!($name => web_sys::WheelEvent);
@@ -302,7 +319,7 @@ impl StaticEvent for MouseEnter {
 
     #[inline]
     fn default_options(preventable: bool) -> EventOptions {
-        EventOptions { bubbles: true, preventable }
+        EventOptions { bubbles: true, preventable, ..EventOptions::default() }
     }
 }
 
@@ -316,7 +333,7 @@ impl StaticEvent for MouseLeave {
 
     #[inline]
     fn default_options(preventable: bool) -> EventOptions {
-        EventOptions { bubbles: true, preventable }
+        EventOptions { bubbles: true, preventable, ..EventOptions::default() }
     }
 }
 
@@ -362,6 +379,9 @@ static_event_impl!(KeyDown => "keydown");
 make_keyboard_event!(KeyUp);
 static_event_impl!(KeyUp => "keyup");
 
+make_keyboard_event!(KeyPress);
+static_event_impl!(KeyPress => "keypress");
+
 make_focus_event!(Focus);
 static_event_impl!(Focus => "focus");
 
@@ -413,6 +433,9 @@ static_event_impl!(AnimationCancel => "animationcancel");
 make_animation_event!(AnimationEnd);
 static_event_impl!(AnimationEnd => "animationend");
 
+make_transition_event!(TransitionEnd);
+static_event_impl!(TransitionEnd => "transitionend");
+
 make_wheel_event!(Wheel);
 static_event_impl!(Wheel => "wheel");
 
@@ -431,6 +454,12 @@ static_event_impl!(ScrollEnd => "scrollend");
 make_event!(Submit => web_sys::Event);
 static_event_impl!(Submit => "submit");
 
+make_event!(Reset => web_sys::Event);
+static_event_impl!(Reset => "reset");
+
+make_event!(Invalid => web_sys::Event);
+static_event_impl!(Invalid => "invalid");
+
 make_event!(Resize => web_sys::UiEvent);
 static_event_impl!(Resize => "resize");
 
@@ -452,6 +481,11 @@ impl Input {
     }
 }
 
+/// Every event type above as `(event name, type name)`, e.g. `("click", "Click")`, sorted by event name.
+///
+/// Generated by `build.rs` from the [`StaticEvent`] impls in this file.
+pub const STATIC_EVENTS: &[(&str, &str)] = include!(concat!(env!("OUT_DIR"), "/static_events.rs"));
+
 make_event!(Change => web_sys::Event);
 static_event_impl!(Change => "change");
 
@@ -468,6 +502,31 @@ impl Change {
     }
 }
 
+// A stable stand-in for the nightly `Event<NAME, T>`, for events without a type of their own such as custom events.
+// It isn't a `StaticEvent`, see `DomBuilder::custom_event`.
+make_event!(Custom => web_sys::Event);
+
+impl Custom {
+    #[inline]
+    pub(crate) fn new(event: web_sys::Event) -> Self {
+        Self { event }
+    }
+
+    #[inline]
+    pub fn event_type(&self) -> String {
+        self.event.type_()
+    }
+
+    /// The underlying event as a more specific type, e.g. `web_sys::CustomEvent` or `web_sys::KeyboardEvent`
+    #[inline]
+    pub fn dyn_event<A>(&self) -> Option<A>
+    where
+        A: JsCast,
+    {
+        self.event.clone().dyn_into().ok()
+    }
+}
+
 make_touch_event!(TouchCancel);
 static_event_impl!(TouchCancel => "touchcancel");
 
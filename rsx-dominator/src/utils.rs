@@ -1,5 +1,5 @@
 use {
-    crate::{dom::EventOptions, traits::StaticEvent},
+    crate::{dom::EventOptions, events::Custom, traits::StaticEvent},
     discard::{Discard, DiscardOnDrop},
     futures_signals::signal::Mutable,
     std::{
@@ -100,6 +100,10 @@ impl EventListener {
         let name = name.into();
         intern(&name);
 
+        if options.once {
+            return Self(Some(gloo_events::EventListener::once_with_options(elem, name, options.into_gloo(), callback)));
+        }
+
         Self(Some(gloo_events::EventListener::new_with_options(elem, name, options.into_gloo(), callback)))
     }
 
@@ -120,7 +124,7 @@ impl EventListener {
         Self(Some(gloo_events::EventListener::once_with_options(
             elem,
             name,
-            EventOptions { bubbles: false, preventable: false }.into_gloo(),
+            EventOptions::default().into_gloo(),
             callback,
         )))
     }
@@ -155,6 +159,16 @@ where
     })
 }
 
+#[inline]
+pub(crate) fn on_custom<F>(element: &EventTarget, name: &str, options: &EventOptions, mut callback: F) -> EventListener
+where
+    F: FnMut(Custom) + 'static,
+{
+    EventListener::new(element, name.to_owned(), options, move |e| {
+        callback(Custom::new(e.clone()));
+    })
+}
+
 // TODO move this into the discard crate
 // TODO verify that this is correct and doesn't leak memory or cause memory safety
 pub(crate) struct ValueDiscard<A>(ManuallyDrop<A>);
@@ -2,9 +2,9 @@ use {
     heck::ToPascalCase,
     proc_macro::TokenStream,
     quote::quote,
-    rsx_parser::tokens::Element,
+    rsx_parser::tokens::{Element, If, Match, Node, Nodes, Spread, is_raw_text_element},
     std::collections::HashSet,
-    syn::{Expr, visit::Visit},
+    syn::{Expr, spanned::Spanned, visit::Visit},
 };
 
 // Visitor to extract identifiers that might need cloning
@@ -12,10 +12,34 @@ struct IdentifierVisitor {
     identifiers: HashSet<String>,
     in_closure_params: bool,
     closure_params: HashSet<String>,
+    // Names bound by `let`, `if let`, `match` and `for` patterns that are currently in scope, e.g. the `el` in
+    // `if let Some(el) = node_ref.get() { el.focus() }`
+    locals: Vec<String>,
+    in_binding: bool,
+    // Also look inside `rsx!(..)` and other expression-like macros
+    macros: bool,
 }
 
 impl IdentifierVisitor {
-    fn new() -> Self { Self { identifiers: HashSet::new(), in_closure_params: false, closure_params: HashSet::new() } }
+    fn new() -> Self {
+        Self {
+            identifiers: HashSet::new(),
+            in_closure_params: false,
+            closure_params: HashSet::new(),
+            locals: Vec::new(),
+            in_binding: false,
+            macros: false,
+        }
+    }
+
+    fn visit_binding(&mut self, pat: &syn::Pat) {
+        let old_in_binding = self.in_binding;
+        self.in_binding = true;
+        self.visit_pat(pat);
+        self.in_binding = old_in_binding;
+    }
+
+    fn with_macros() -> Self { Self { macros: true, ..Self::new() } }
 }
 
 impl<'ast> Visit<'ast> for IdentifierVisitor {
@@ -35,14 +59,87 @@ impl<'ast> Visit<'ast> for IdentifierVisitor {
     fn visit_pat_ident(&mut self, node: &'ast syn::PatIdent) {
         if self.in_closure_params {
             self.closure_params.insert(node.ident.to_string());
+        } else if self.in_binding {
+            self.locals.push(node.ident.to_string());
         }
         syn::visit::visit_pat_ident(self, node);
     }
 
+    // Bindings are only in scope after their initializer, and until the end of their block, arm or body
+
+    fn visit_block(&mut self, node: &'ast syn::Block) {
+        let scope = self.locals.len();
+        syn::visit::visit_block(self, node);
+        self.locals.truncate(scope);
+    }
+
+    fn visit_local(&mut self, node: &'ast syn::Local) {
+        if let Some(init) = &node.init {
+            self.visit_local_init(init);
+        }
+        self.visit_binding(&node.pat);
+    }
+
+    fn visit_expr_let(&mut self, node: &'ast syn::ExprLet) {
+        self.visit_expr(&node.expr);
+        self.visit_binding(&node.pat);
+    }
+
+    fn visit_expr_if(&mut self, node: &'ast syn::ExprIf) {
+        let scope = self.locals.len();
+        self.visit_expr(&node.cond);
+        self.visit_block(&node.then_branch);
+        self.locals.truncate(scope);
+
+        if let Some((_, else_branch)) = &node.else_branch {
+            self.visit_expr(else_branch);
+        }
+    }
+
+    fn visit_expr_while(&mut self, node: &'ast syn::ExprWhile) {
+        let scope = self.locals.len();
+        self.visit_expr(&node.cond);
+        self.visit_block(&node.body);
+        self.locals.truncate(scope);
+    }
+
+    fn visit_expr_for_loop(&mut self, node: &'ast syn::ExprForLoop) {
+        self.visit_expr(&node.expr);
+        let scope = self.locals.len();
+        self.visit_binding(&node.pat);
+        self.visit_block(&node.body);
+        self.locals.truncate(scope);
+    }
+
+    fn visit_arm(&mut self, node: &'ast syn::Arm) {
+        let scope = self.locals.len();
+        self.visit_binding(&node.pat);
+        if let Some((_, guard)) = &node.guard {
+            self.visit_expr(guard);
+        }
+        self.visit_expr(&node.body);
+        self.locals.truncate(scope);
+    }
+
+    fn visit_macro(&mut self, node: &'ast syn::Macro) {
+        if !self.macros {
+            return;
+        }
+
+        if node.path.is_ident("rsx") {
+            if let Ok(Nodes(nodes)) = node.parse_body::<Nodes>() {
+                nodes.iter().for_each(|node| visit_node(self, node));
+            }
+        } else if let Ok(exprs) = node.parse_body_with(syn::punctuated::Punctuated::<Expr, syn::Token![,]>::parse_terminated)
+        {
+            exprs.iter().for_each(|expr| self.visit_expr(expr));
+        }
+    }
+
     fn visit_expr_path(&mut self, node: &'ast syn::ExprPath) {
         if let Some(ident) = node.path.get_ident() {
             let name = ident.to_string();
-            if !self.closure_params.contains(&name) && !self.in_closure_params {
+            if !self.closure_params.contains(&name) && !self.in_closure_params && !self.locals.contains(&name) {
                 self.identifiers.insert(name);
             }
         }
@@ -82,33 +179,84 @@ fn modify_closure_to_use_clones(expr: &Expr, captured_vars: &HashSet<String>) ->
 }
 
 fn generate_component_code(element: &Element) -> proc_macro2::TokenStream {
-    let component_name = &element.ident;
+    let Some(component_name) = element.name.as_ident() else {
+        return syn::Error::new_spanned(&element.name, format!("Invalid component name `{}`", element.name))
+            .to_compile_error();
+    };
     let props_struct_name = syn::Ident::new(&format!("{}Props", component_name), component_name.span());
 
-    // Generate props struct instantiation from attributes
-    let mut prop_assignments = Vec::new();
+    // Set each prop on the props builder, missing ones are filled in (or reported) by `build()`
+    let mut prop_setters = Vec::new();
 
     for prop in &element.props {
-        let prop_name = &prop.name;
-        let prop_value = &prop.value;
+        let Some(prop_name) = prop.name.as_ident() else {
+            return syn::Error::new_spanned(&prop.name, format!("Invalid component prop name `{}`", prop.name))
+                .to_compile_error();
+        };
+        if prop.style.is_some() {
+            return syn::Error::new_spanned(&prop.name, "`style={{ .. }}` objects only work on elements").to_compile_error();
+        }
+        let prop_value = generate_prop_code(&prop.value);
 
-        prop_assignments.push(quote! {
-            #prop_name: #prop_value
+        prop_setters.push(quote::quote_spanned! {prop_name.span()=>
+            .#prop_name(::rustsx::component::IntoProp::into_prop(#prop_value))
         });
     }
 
-    // Call the component function with the props
-    quote! {
-        #component_name(#props_struct_name {
-            #(#prop_assignments),*
-        })
+    // A spread provides every prop, explicit ones then override it
+    let builder = match element.spreads.as_slice() {
+        [] => quote::quote_spanned! {component_name.span()=> #props_struct_name::builder() },
+        [Spread(spread)] => quote::quote_spanned! {component_name.span()=> #props_struct_name::into_builder(#spread) },
+        [_, Spread(extra), ..] => {
+            return syn::Error::new_spanned(extra, "Components only accept a single {..props} spread").to_compile_error();
+        }
+    };
+    let build = quote::quote_spanned! {component_name.span()=> .build() };
+
+    // Nested markup becomes the `children` prop
+    let children = element
+        .children
+        .iter()
+        .map(|child| child.as_ref())
+        .filter(|child| !matches!(child, Node::Text(text) if text.trim().is_empty()))
+        .collect::<Vec<_>>();
+    if children.is_empty() {
+        return quote! {
+            #component_name(#builder #(#prop_setters)* #build)
+        };
     }
+
+    // Set through `AcceptsChildren` rather than the builder's own setter, so a component without a `children`
+    // parameter gets its diagnostic instead of a missing method
+    let clones = generate_branch_clones(children.iter().copied(), &HashSet::new());
+    let methods = generate_children_methods(children.iter().copied());
+    let accepts_children = quote::quote_spanned! {component_name.span()=>
+        ::rustsx::component::AcceptsChildren::children
+    };
+
+    quote! {{
+        let props = #accepts_children(#builder #(#prop_setters)*, {
+            #(#clones)*
+            ::rustsx::component::Children::new(move |dom| {
+                #(#clones)*
+                dom #(#methods)*
+            })
+        })
+        #build;
+        #component_name(props)
+    }}
 }
 
 fn generate_dom_code(element: &Element) -> proc_macro2::TokenStream {
-    let tag_name = &element.ident;
+    let tag_name = &element.name;
     let tag_str = tag_name.to_string();
 
+    // `<For>` becomes `children_signal_vec` on its parent, see `generate_children_methods`
+    if tag_str == "For" {
+        return syn::Error::new_spanned(tag_name, "`<For>` needs a parent element, e.g. `<ul><For ..>..</For></ul>`")
+            .to_compile_error();
+    }
+
     // Check if this is a component (starts with uppercase)
     let first_char = tag_str.chars().next().unwrap_or('a');
     if first_char.is_uppercase() {
@@ -119,16 +267,23 @@ fn generate_dom_code(element: &Element) -> proc_macro2::TokenStream {
     // Generate attributes for HTML elements
     let mut methods = Vec::new();
 
+    // Spreads go first so explicit props override them
+    for Spread(spread) in &element.spreads {
+        methods.push(quote! {
+            .apply(|dom| ::rustsx::attr::Spread::spread(#spread, dom))
+        });
+    }
+
     for prop in &element.props {
-        let attr_code = generate_attribute_code(prop, &tag_str);
-        methods.push(attr_code);
+        match generate_attribute_code(prop, element) {
+            Ok(attr_code) => methods.push(attr_code),
+            Err(e) => return e.to_compile_error(),
+        }
     }
 
     // Handle style and script tags specially - their children should be treated as raw text
     // Exception: script tags with 'src' attribute should be treated as normal HTML elements
-    let has_src_attr = tag_str == "script" && element.props.iter().any(|prop| prop.name == "src");
-
-    if (tag_str == "style" || tag_str == "script") && !has_src_attr {
+    if is_raw_text_element(&element.name, &element.props) {
         if !element.children.is_empty() {
             let raw_content = extract_raw_content(&element.children);
             let text_method = quote! {
@@ -138,151 +293,362 @@ fn generate_dom_code(element: &Element) -> proc_macro2::TokenStream {
         }
     } else {
         // Generate children if any (normal HTML elements)
-        if !element.children.is_empty() {
-            let mut children = Vec::new();
-            for child in &element.children {
-                let child_code = generate_child_code(child);
-                children.push(child_code);
-            }
-
-            let children = children.into_iter().flatten().collect::<Vec<_>>();
-            let children_method = quote! {
-                .children(&mut [
-                    #(#children),*
-                ])
-            };
-            methods.push(children_method);
-        }
+        methods.extend(generate_children_methods(element.children.iter().map(Box::as_ref)));
     }
 
-    // Generate dominator code structure
-    quote! {
-        html!(#tag_str, {
-            #(#methods)*
-        })
+    // Build it as the tag's `web_sys` type, so refs and event handlers get e.g. an `HtmlInputElement`
+    let element_type = generate_element_type(element);
+    match element.svg {
+        true => quote! {
+            svg!(#tag_str => #element_type, {
+                #(#methods)*
+            })
+        },
+        false => quote! {
+            html!(#tag_str => #element_type, {
+                #(#methods)*
+            })
+        },
     }
 }
 
-fn generate_attribute_code(prop: &rsx_parser::tokens::Prop, tag_name: &str) -> proc_macro2::TokenStream {
+// The `web_sys` type of an element, e.g. `HtmlInputElement` for `<input>` or `SvgCircleElement` for `<circle>`
+fn generate_element_type(element: &Element) -> proc_macro2::TokenStream {
+    let tag = element.name.to_string();
+    let element_type = match element.svg {
+        true => rsx_parser::svg_web_sys_type(&tag),
+        false => rsx_parser::web_sys_type(&tag),
+    };
+    let element_type = syn::Ident::new(&element_type, element.name.span());
+    quote! { ::rustsx::web_sys::#element_type }
+}
+
+// `onclick={handler}`, `on:my-event={handler}` for events without a type in `dominator::events`, and either with
+// modifiers such as `onclick:prevent:stop={handler}`
+fn generate_event_code(prop: &rsx_parser::tokens::Prop, element: &Element) -> syn::Result<proc_macro2::TokenStream> {
     let attr_name = prop.name.to_string();
     let value = &prop.value;
 
-    // Check if this is an event handler
-    if attr_name.starts_with("on") {
-        // Map HTML event names to dominator events
-        let event_type = match attr_name.as_str() {
-            "onclick" => quote! { dominator::events::Click },
-            "onmousedown" => quote! { dominator::events::MouseDown },
-            "onmouseup" => quote! { dominator::events::MouseUp },
-            "onmousemove" => quote! { dominator::events::MouseMove },
-            "ondblclick" => quote! { dominator::events::DoubleClick },
-            "oncontextmenu" => quote! { dominator::events::ContextMenu },
-            "onpointerover" => quote! { dominator::events::PointerOver },
-            "onpointerenter" => quote! { dominator::events::PointerEnter },
-            "onpointerdown" => quote! { dominator::events::PointerDown },
-            "onpointermove" => quote! { dominator::events::PointerMove },
-            "onpointerup" => quote! { dominator::events::PointerUp },
-            "onpointercancel" => quote! { dominator::events::PointerCancel },
-            "onpointerout" => quote! { dominator::events::PointerOut },
-            "onpointerleave" => quote! { dominator::events::PointerLeave },
-            "ongotpointercapture" => quote! { dominator::events::GotPointerCapture },
-            "onlostpointercapture" => quote! { dominator::events::LostPointerCapture },
-            "onkeydown" => quote! { dominator::events::KeyDown },
-            "onkeyup" => quote! { dominator::events::KeyUp },
-            "onfocus" => quote! { dominator::events::Focus },
-            "onblur" => quote! { dominator::events::Blur },
-            "onfocusin" => quote! { dominator::events::FocusIn },
-            "onfocusout" => quote! { dominator::events::FocusOut },
-            "ondragstart" => quote! { dominator::events::DragStart },
-            "ondrag" => quote! { dominator::events::Drag },
-            "ondragend" => quote! { dominator::events::DragEnd },
-            "ondragover" => quote! { dominator::events::DragOver },
-            "ondragenter" => quote! { dominator::events::DragEnter },
-            "ondragleave" => quote! { dominator::events::DragLeave },
-            "ondrop" => quote! { dominator::events::Drop },
-            "oninput" => quote! { dominator::events::Input },
-            "onbeforeinput" => quote! { dominator::events::BeforeInput },
-            "onanimationstart" => quote! { dominator::events::AnimationStart },
-            "onanimationiteration" => quote! { dominator::events::AnimationIteration },
-            "onanimationcancel" => quote! { dominator::events::AnimationCancel },
-            "onanimationend" => quote! { dominator::events::AnimationEnd },
-            "onwheel" => quote! { dominator::events::Wheel },
-            "onload" => quote! { dominator::events::Load },
-            "onerror" => quote! { dominator::events::Error },
-            "onscroll" => quote! { dominator::events::Scroll },
-            "onscrollend" => quote! { dominator::events::ScrollEnd },
-            "onsubmit" => quote! { dominator::events::Submit },
-            "onresize" => quote! { dominator::events::Resize },
-            "onselectionchange" => quote! { dominator::events::SelectionChange },
-            "onchange" => quote! { dominator::events::Change },
-            "ontouchcancel" => quote! { dominator::events::TouchCancel },
-            "ontouchend" => quote! { dominator::events::TouchEnd },
-            "ontouchmove" => quote! { dominator::events::TouchMove },
-            "ontouchstart" => quote! { dominator::events::TouchStart },
-            _ => quote! { web_sys::Event }, // fallback
-        };
+    let mut parts = attr_name.split(':');
+    let event = parts.next().unwrap_or_default();
+    // `on:my-event` listens for any event by name, with the untyped `events::Custom`
+    let custom = if event == "on" { parts.next() } else { None };
+    let modifiers = parts.collect::<Vec<_>>();
+
+    let event_type = match custom {
+        Some(_) => quote! { ::rustsx::dominator::events::Custom },
+        None => match rsx_parser::events::static_event(event) {
+            Some(event_type) => {
+                let event_type = syn::Ident::new(event_type, prop.name.span());
+                quote! { ::rustsx::dominator::events::#event_type }
+            }
+            None => {
+                let expected = rsx_parser::events::static_event_props().collect::<Vec<_>>().join("`, `");
+                let name = event.trim_start_matches("on");
+                return Err(syn::Error::new_spanned(
+                    &prop.name,
+                    format!("Unknown event `{event}`, expected one of `{expected}`, or `on:{name}` for any other event"),
+                ));
+            }
+        },
+    };
+
+    // Modifiers either run before the handler, in the order they're written, or change the listener's options
+    let mut guards = Vec::new();
+    let (mut prevent, mut capture, mut once, mut passive) = (false, false, false, false);
+    for modifier in &modifiers {
+        match *modifier {
+            "prevent" => {
+                prevent = true;
+                guards.push(quote! { event.prevent_default(); });
+            }
+            "stop" => guards.push(quote! { event.stop_propagation(); }),
+            "self" => guards.push(quote! {
+                if event.target() != event.current_target() {
+                    return;
+                }
+            }),
+            "capture" => capture = true,
+            "once" => once = true,
+            "passive" => passive = true,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &prop.name,
+                    format!(
+                        "Unknown event modifier `:{modifier}`, expected `:prevent`, `:stop`, `:once`, `:capture`, \
+                         `:passive` or `:self`"
+                    ),
+                ));
+            }
+        }
+    }
 
-        // Generate event handler code with automatic cloning
-        let captured_vars = extract_captured_variables(value);
+    if prevent && passive {
+        return Err(syn::Error::new_spanned(&prop.name, "`:passive` listeners can't `:prevent` the default action"));
+    }
 
-        if captured_vars.is_empty() {
-            // No captured variables, use original handler
-            quote! {
-                .event(move |event: #event_type| (#value)(event))
+    // `|event, element| ..` also gets the element, typed by its tag
+    let with_element = matches!(value, Expr::Closure(closure) if closure.inputs.len() == 2);
+    let call = match with_element {
+        true => {
+            let element_type = generate_element_type(element);
+            quote::quote_spanned! {value.span()=>
+                ::rustsx::event::handler_with_element::<#event_type, #element_type, _>
             }
-        } else {
-            // Generate clone statements for captured variables
-            let clone_stmts: Vec<_> = captured_vars
-                .iter()
-                .map(|var| {
-                    let var_ident = syn::Ident::new(var, proc_macro2::Span::call_site());
-                    let clone_ident = syn::Ident::new(&format!("{}_clone", var), proc_macro2::Span::call_site());
-                    quote! { let #clone_ident = #var_ident.clone(); }
-                })
-                .collect();
-
-            // Create a new closure that uses the cloned variables
-            let modified_closure = modify_closure_to_use_clones(value, &captured_vars);
-
-            quote! {
-                .event({
-                    #(#clone_stmts)*
-                    move |event: #event_type| (#modified_closure)(event)
-                })
+        }
+        false => quote::quote_spanned! {value.span()=> ::rustsx::event::handler::<#event_type, _> },
+    };
+    let args = match with_element {
+        true => quote::quote_spanned! {value.span()=> (event, __element.clone()) },
+        false => quote! { (event) },
+    };
+
+    // Generate event handler code with automatic cloning
+    let captured_vars = extract_captured_variables(value);
+
+    let listener = if captured_vars.is_empty() {
+        // No captured variables, use original handler
+        let invoke = quote::quote_spanned! {value.span()=> (#call(#value))#args };
+        quote! {
+            move |event: #event_type| {
+                #(#guards)*
+                #invoke
             }
         }
     } else {
-        // Some attributes need to be set as properties instead of attributes
-        // to trigger browser behavior correctly (e.g., script loading)
-        let should_use_prop = matches!(
-            (tag_name, attr_name.as_str()),
-            ("script", "src")
-                | ("img", "src")
-                | ("iframe", "src")
-                | ("video", "src")
-                | ("audio", "src")
-                | ("source", "src")
-                | ("input", "value")
-                | ("textarea", "value")
-                | ("select", "value")
-                | ("input", "checked")
-                | ("option", "selected")
-        );
-
-        if should_use_prop {
-            // Use .prop() for properties that need to trigger browser behavior
-            quote! {
-                .prop(#attr_name, #value)
+        // Generate clone statements for captured variables
+        let clone_stmts: Vec<_> = captured_vars
+            .iter()
+            .map(|var| {
+                let var_ident = syn::Ident::new(var, proc_macro2::Span::call_site());
+                let clone_ident = syn::Ident::new(&format!("{}_clone", var), proc_macro2::Span::call_site());
+                quote! { let #clone_ident = #var_ident.clone(); }
+            })
+            .collect();
+
+        // Create a new closure that uses the cloned variables
+        let modified_closure = modify_closure_to_use_clones(value, &captured_vars);
+        let invoke = quote::quote_spanned! {value.span()=> (#call(#modified_closure))#args };
+
+        quote! {{
+            #(#clone_stmts)*
+            move |event: #event_type| {
+                #(#guards)*
+                #invoke
             }
-        } else {
-            // Use .attr() for regular HTML attributes
-            quote! {
-                .attr(#attr_name, #value)
+        }}
+    };
+
+    // Modifiers start from the options `.event()` and `.custom_event()` use and only change their own, so adding one
+    // doesn't move the listener to another phase. `:passive` listeners aren't preventable.
+    let method = match (custom, modifiers.is_empty()) {
+        (Some(name), true) => quote! { .custom_event(#name, #listener) },
+        (None, true) => quote! { .event(#listener) },
+        (custom, false) => {
+            let defaults = match custom {
+                Some(_) => quote! { ::rustsx::dominator::EventOptions::bubbles() },
+                None => {
+                    let preventable = !passive;
+                    quote! { <#event_type as ::rustsx::dominator::traits::StaticEvent>::default_options(#preventable) }
+                }
+            };
+            let capture = capture.then(|| quote! { bubbles: false, });
+            let prevent = (prevent && custom.is_some()).then(|| quote! { preventable: true, });
+            let once = once.then(|| quote! { once: true, });
+            let options = quote! {
+                &::rustsx::dominator::EventOptions { #capture #prevent #once ..#defaults }
+            };
+
+            match custom {
+                Some(name) => quote! { .custom_event_with_options(#name, #options, #listener) },
+                None => quote! { .event_with_options(#options, #listener) },
             }
         }
+    };
+
+    match with_element {
+        true => Ok(quote! {
+            .apply(|dom| {
+                let __element = ::rustsx::dominator::DomBuilder::__internal_element(&dom);
+                dom #method
+            })
+        }),
+        false => Ok(method),
     }
 }
 
+fn generate_attribute_code(prop: &rsx_parser::tokens::Prop, element: &Element) -> syn::Result<proc_macro2::TokenStream> {
+    let attr_name = prop.name.to_string();
+    let tag_name = &element.name.to_string();
+    let value = &prop.value;
+
+    if let Some(style) = &prop.style {
+        // `DomBuilder::style` is only implemented for HTML elements
+        if element.svg {
+            return Err(syn::Error::new_spanned(
+                &prop.name,
+                "`style={{ .. }}` objects only work on HTML elements, use a `style` string on SVG elements",
+            ));
+        }
+        Ok(generate_style_code(style))
+    } else if attr_name.starts_with("on") {
+        // Event handlers
+        generate_event_code(prop, element)
+    } else if attr_name == "ref" {
+        // `ref={node_ref}` fills a `NodeRef` with the element, typed by its tag
+        let bind = quote::quote_spanned! {value.span()=>
+            ::rustsx::node_ref::bind(dom, &#value)
+        };
+        Ok(quote! {
+            .apply(|dom| #bind)
+        })
+    } else if let Some(("bind", binding)) = prop.name.namespace() {
+        generate_bind_code(prop, binding, tag_name)
+    } else if let Some(("class", class)) = prop.name.namespace() {
+        // `class:active={bool_or_signal}` toggles a single class
+        let value = generate_reactive_code(value);
+        Ok(quote! {
+            .apply(|dom| ::rustsx::class::toggle(dom, #class, #value))
+        })
+    } else if attr_name == "class" {
+        // Classes go through the class list rather than the attribute, so `class`, `class={[..]}` and `class:name`
+        // can all be used on one element
+        let classes = match value {
+            Expr::Array(array) => array.elems.iter().collect(),
+            value => vec![value],
+        };
+        Ok(classes
+            .into_iter()
+            .map(|class| generate_kind_code(class, quote::quote_spanned!(class.span()=> class(dom, value))))
+            .collect())
+    } else if let Some((prefix, _)) = prop.name.namespace() {
+        // Namespaced attributes, e.g. xlink:href or xml:lang
+        let Some(namespace) = rsx_parser::attr_props::namespace_uri(prefix) else {
+            return Err(syn::Error::new_spanned(&prop.name, format!("Unknown attribute namespace `{prefix}`")));
+        };
+
+        Ok(quote! {
+            .attr_ns(#namespace, #attr_name, #value)
+        })
+    } else {
+        // SVG attributes aren't in the MDN data, and are all set as text, case-sensitively (e.g. `viewBox`)
+        let attr_type = match element.svg {
+            true => rsx_parser::attr_types::AttrType::Text,
+            false => rsx_parser::attr_types::attr_type(tag_name, &attr_name),
+        };
+
+        // Boolean attributes (`<input disabled />`, `required={true}`, `hidden={signal}`) are toggled on and off, see
+        // `rustsx::attr::BooleanValue`. Strings are set as written, e.g. `disabled="disabled"` or
+        // `hidden="until-found"`, and bools on any other attribute as `"true"` or `"false"`, e.g. `draggable={true}`.
+        let is_str = matches!(value, Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(_), .. }));
+        if !element.svg && !is_str && rsx_parser::attr_types::is_boolean(tag_name, &attr_name) {
+            let method = quote::quote_spanned!(value.span()=> boolean(dom, #attr_name, value));
+            return Ok(generate_kind_code(value, method));
+        }
+
+        // Number and URL attributes only take values of those types, see `rustsx::attr::AttrValue`. Literals have
+        // already been checked against the attribute's type by `rsx_parser::validate`.
+        let kind = match (attr_type, value) {
+            (_, Expr::Lit(_)) => "Text",
+            (rsx_parser::attr_types::AttrType::Number, _) => "Number",
+            (rsx_parser::attr_types::AttrType::Url, _) => "Url",
+            _ => "Text",
+        };
+        let kind = syn::Ident::new(kind, value.span());
+
+        // Values may be a `Mutable`, a signal or an `Option`, and whether it's set as an attribute or a DOM property
+        // depends on the element, so both are decided at runtime by `rsx_parser::attr_props`
+        let method = quote::quote_spanned! {value.span()=>
+            attr::<::rustsx::attr::kinds::#kind, _, _>(dom, #attr_name, value)
+        };
+        Ok(generate_kind_code(value, method))
+    }
+}
+
+// `.apply(|dom| ..)` sorting `value` into its `rustsx::reactive` kind and calling `method` on that, with the value
+// bound as `value`, e.g. `attr(dom, "title", value)`
+fn generate_kind_code(value: &Expr, method: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let call = quote::quote_spanned! {value.span()=>
+        (&&&::rustsx::reactive::Probe(&value)).__rsx_kind().#method
+    };
+    quote! {
+        .apply(|dom| {
+            use ::rustsx::reactive::{ViaMutable as _, ViaSignal as _, ViaValue as _};
+            // A `match` keeps temporaries alive, e.g. `title={name.to_string().as_str()}`
+            match #value {
+                value => #call,
+            }
+        })
+    }
+}
+
+// `bind:value={mutable}`, `bind:checked={mutable}` or `bind:group={mutable}` on a form control, see `rustsx::bind`
+fn generate_bind_code(
+    prop: &rsx_parser::tokens::Prop,
+    binding: &str,
+    tag_name: &str,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let tags: &[&str] = match binding {
+        "value" => &["input", "textarea", "select"],
+        "checked" | "group" => &["input"],
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &prop.name,
+                format!("Unknown binding `bind:{binding}`, expected `bind:value`, `bind:checked` or `bind:group`"),
+            ));
+        }
+    };
+
+    if !tags.contains(&tag_name) {
+        let tags = tags.iter().map(|tag| format!("`<{tag}>`")).collect::<Vec<_>>().join(", ");
+        return Err(syn::Error::new_spanned(&prop.name, format!("`bind:{binding}` only works on {tags}")));
+    }
+
+    // The mutable is cloned so it can still be used elsewhere, like `{mutable}` to show the value
+    let helper = syn::Ident::new(binding, prop.name.span());
+    let value = &prop.value;
+    let bind = quote::quote_spanned! {value.span()=>
+        ::rustsx::bind::#helper(dom, ::core::clone::Clone::clone(&#value))
+    };
+    Ok(quote! {
+        .apply(|dom| #bind)
+    })
+}
+
+// `style={{ color: signal, "margin-top": "4px" !important }}`, each property set or bound on its own
+fn generate_style_code(style: &[rsx_parser::tokens::StyleProperty]) -> proc_macro2::TokenStream {
+    let properties = style.iter().map(|rsx_parser::tokens::StyleProperty { name, value, important }| {
+        generate_kind_code(value, quote::quote_spanned!(value.span()=> style(dom, #name, #important, value)))
+    });
+
+    quote! { #(#properties)* }
+}
+
+// Sorts a value into a `rustsx::reactive::Reactive` at compile time, so plain values, `Mutable`s and signals can all
+// be handed to the same runtime helper
+fn generate_reactive_code(value: &Expr) -> proc_macro2::TokenStream {
+    // The block and the call are spanned at the value, so a value of the wrong type is underlined there rather than
+    // the whole macro. The `use` isn't, as it would then be reported as unused on the value.
+    let sort = quote::quote_spanned! {value.span()=> (&&&::rustsx::reactive::Probe(&value)).__rsx_kind().reactive(value) };
+    let body = quote! {
+        use ::rustsx::reactive::{ViaMutable as _, ViaSignal as _, ViaValue as _};
+        let value = #value;
+        #sort
+    };
+    quote::quote_spanned! {value.span()=> { #body } }
+}
+
+// Like `generate_reactive_code`, but sorts a component prop for `rustsx::component::IntoProp`
+fn generate_prop_code(value: &Expr) -> proc_macro2::TokenStream {
+    let sort = quote::quote_spanned! {value.span()=> (&&&::rustsx::reactive::Probe(&value)).__rsx_kind().prop(value) };
+    let body = quote! {
+        use ::rustsx::reactive::{ViaMutable as _, ViaSignal as _, ViaValue as _};
+        let value = #value;
+        #sort
+    };
+    quote::quote_spanned! {value.span()=> { #body } }
+}
+
 fn extract_raw_content(children: &[Box<rsx_parser::tokens::Node>]) -> String {
     let mut content = String::new();
 
@@ -297,10 +663,17 @@ fn extract_raw_content(children: &[Box<rsx_parser::tokens::Node>]) -> String {
                 content.push_str(&quote!(#expr).to_string());
                 content.push('}');
             }
+            // Raw text is never parsed into conditionals
+            rsx_parser::tokens::Node::If(_) | rsx_parser::tokens::Node::Match(_) => {}
+            rsx_parser::tokens::Node::Fragment(fragment) => {
+                content.push_str("<>");
+                content.push_str(&extract_raw_content(&fragment.children));
+                content.push_str("</>");
+            }
             rsx_parser::tokens::Node::Element(element) => {
                 // Convert nested elements to text representation
                 content.push('<');
-                content.push_str(&element.ident.to_string());
+                content.push_str(&element.name.to_string());
 
                 // Add attributes
                 for prop in &element.props {
@@ -319,7 +692,7 @@ fn extract_raw_content(children: &[Box<rsx_parser::tokens::Node>]) -> String {
                     content.push('>');
                     content.push_str(&extract_raw_content(&element.children));
                     content.push_str("</");
-                    content.push_str(&element.ident.to_string());
+                    content.push_str(&element.name.to_string());
                     content.push('>');
                 }
             }
@@ -329,205 +702,770 @@ fn extract_raw_content(children: &[Box<rsx_parser::tokens::Node>]) -> String {
     content
 }
 
-fn generate_child_code(child: &rsx_parser::tokens::Node) -> Vec<proc_macro2::TokenStream> {
-    use rsx_parser::tokens::Node;
+// A child in a position that needs `Dom`s rather than builder methods, e.g. the root of `rsx!` or a branch of `{if ..}`
+enum DomCode {
+    // A single `Dom`
+    One(proc_macro2::TokenStream),
+    // A `Vec<Dom>`
+    Many(proc_macro2::TokenStream),
+}
+
+// Collects `DomCode`s into a single `Vec<Dom>` expression
+fn collect_doms(doms: Vec<DomCode>) -> proc_macro2::TokenStream {
+    if doms.iter().all(|dom| matches!(dom, DomCode::One(_))) {
+        let doms = doms.into_iter().map(|dom| match dom {
+            DomCode::One(dom) | DomCode::Many(dom) => dom,
+        });
+        return quote! { ::std::vec![#(#doms),*] };
+    }
+
+    let pushes = doms.into_iter().map(|dom| match dom {
+        DomCode::One(dom) => quote! { __doms.push(#dom); },
+        DomCode::Many(doms) => quote! { __doms.extend(#doms); },
+    });
+    quote! {{
+        let mut __doms = ::std::vec::Vec::new();
+        #(#pushes)*
+        __doms
+    }}
+}
+
+// Sorts a `{child}` expression into one of the `rustsx::child` wrappers
+fn generate_child_expr(expr: &Expr) -> proc_macro2::TokenStream {
+    quote::quote_spanned! {expr.span()=> {
+        #[allow(unused_imports)]
+        use ::rustsx::{
+            child::{
+                ViaChild as _, ViaDomSignal as _, ViaIter as _, ViaMutable as _, ViaOptionSignal as _,
+                ViaSignalVec as _, ViaTextSignal as _, ViaUnsupported as _,
+            },
+            futures_signals::{signal::SignalExt as _, signal_vec::SignalVecExt as _},
+        };
+        (#expr).__rsx_child()
+    }}
+}
+
+// Literals are text as-is, this also avoids calling methods on an ambiguous `{integer}`
+fn literal_text(expr: &Expr) -> Option<String> {
+    let Expr::Lit(syn::ExprLit { lit, .. }) = expr else { return None };
+    match lit {
+        syn::Lit::Str(lit) => Some(lit.value()),
+        syn::Lit::Char(lit) => Some(lit.value().to_string()),
+        syn::Lit::Int(lit) => Some(lit.base10_digits().to_owned()),
+        syn::Lit::Float(lit) => Some(lit.base10_digits().to_owned()),
+        syn::Lit::Bool(lit) => Some(lit.value.to_string()),
+        _ => None,
+    }
+}
+
+fn generate_child_code(child: &Node) -> Vec<DomCode> {
     match child {
-        Node::Element(element) => vec![generate_dom_code(element)],
-        Node::Text(text) => {
-            let mut depth = 0;
-            let mut buf = String::new();
-            let mut doms = Vec::<proc_macro2::TokenStream>::new();
-            let mut itr = text.chars().peekable();
-
-            while let Some(c) = itr.next() {
-                if c == '{' && itr.peek() != Some(&'{') {
-                    depth += 1;
-                    if depth == 1 {
-                        // Starting a new expression - push any accumulated text
-                        if !buf.is_empty() {
-                            doms.push(quote! { Dom::text(#buf) });
-                            buf.clear();
-                        }
-                    } else {
-                        // Nested brace inside expression - add to buffer
-                        buf.push(c);
-                    }
-                } else if c == '}' && itr.peek() != Some(&'}') {
-                    if depth > 0 {
-                        depth -= 1;
-                        if depth == 0 {
-                            // Closing the expression - parse it
-                            if !buf.is_empty() {
-                                if let Ok(expr) = syn::parse_str::<Expr>(&buf) {
-                                    doms.push(generate_child_code(&Node::Expression(expr)).into_iter().next().unwrap());
-                                }
-                                buf.clear();
-                            }
-                        } else {
-                            // Nested brace inside expression - add to buffer
-                            buf.push(c);
-                        }
-                    } else {
-                        // Regular text
-                        buf.push(c);
-                    }
-                } else {
-                    buf.push(c);
-                }
+        Node::Element(element) => vec![DomCode::One(generate_dom_code(element))],
+        // Fragments don't produce a node, their children are spliced into the parent
+        Node::Fragment(fragment) => fragment.children.iter().flat_map(|child| generate_child_code(child)).collect(),
+        Node::Text(text) => vec![DomCode::One(quote! { Dom::text(#text) })],
+        Node::Expression(expr) => match literal_text(expr) {
+            Some(text) => vec![DomCode::One(quote::quote_spanned! {expr.span()=> Dom::text(#text) })],
+            None => {
+                let child = generate_child_expr(expr);
+                vec![DomCode::Many(quote::quote_spanned! {expr.span()=> ::rustsx::child::IntoDoms::into_doms(#child) })]
             }
+        },
+        Node::If(If { branches, .. }) => vec![DomCode::One(
+            syn::Error::new_spanned(&branches[0].0, "`{if ..}` needs a parent element, e.g. `<div>{if ..}</div>`")
+                .to_compile_error(),
+        )],
+        Node::Match(Match { expr, .. }) => vec![DomCode::One(
+            syn::Error::new_spanned(expr, "`{match ..}` needs a parent element, e.g. `<div>{match ..}</div>`")
+                .to_compile_error(),
+        )],
+    }
+}
+
+// A child of an element, as `Dom`s that are added together by one `.children([..])` call, or as a `DomBuilder`
+// method of its own for anything that isn't a plain `Dom`
+enum ChildCode {
+    Dom(proc_macro2::TokenStream),
+    Method(proc_macro2::TokenStream),
+}
+
+// Children of an element, as `DomBuilder` methods. Runs of `Dom`s are batched, so the method chain only grows with
+// the reactive children and long static lists stay within the recursion limit.
+fn generate_children_methods<'a>(children: impl IntoIterator<Item = &'a Node>) -> Vec<proc_macro2::TokenStream> {
+    let mut methods = Vec::new();
+    let mut doms = Vec::new();
+    let flush = |methods: &mut Vec<_>, doms: &mut Vec<_>| {
+        if !doms.is_empty() {
+            let doms = doms.drain(..);
+            methods.push(quote! { .children([#(#doms),*]) });
+        }
+    };
 
-            if depth != 0 {
-                panic!("Expected '}}', found EOF")
+    for code in children.into_iter().flat_map(generate_child_methods) {
+        match code {
+            ChildCode::Dom(dom) => doms.push(dom),
+            ChildCode::Method(method) => {
+                flush(&mut methods, &mut doms);
+                methods.push(method);
             }
+        }
+    }
+    flush(&mut methods, &mut doms);
+    methods
+}
 
-            if !buf.is_empty() {
-                doms.push(quote! { Dom::text(#buf) });
-                buf.clear();
+fn generate_child_methods(child: &Node) -> Vec<ChildCode> {
+    match child {
+        Node::Fragment(fragment) => fragment.children.iter().flat_map(|child| generate_child_methods(child)).collect(),
+        Node::If(if_node) => vec![ChildCode::Method(generate_if_code(if_node))],
+        Node::Match(match_node) => vec![ChildCode::Method(generate_match_code(match_node))],
+        Node::Expression(expr) if literal_text(expr).is_none() => {
+            let child = generate_child_expr(expr);
+            vec![ChildCode::Method(quote::quote_spanned! {expr.span()=>
+                .apply(|dom| ::rustsx::child::IntoChild::append_to(#child, dom))
+            })]
+        }
+        Node::Element(element) if element.name == "For" => {
+            let code = generate_for_code(element).unwrap_or_else(|e| {
+                let error = e.to_compile_error();
+                quote! { .apply(|dom| { #error dom }) }
+            });
+            vec![ChildCode::Method(code)]
+        }
+        _ => generate_child_code(child)
+            .into_iter()
+            .map(|dom| match dom {
+                DomCode::One(dom) => ChildCode::Dom(dom),
+                DomCode::Many(doms) => ChildCode::Method(quote! { .children(#doms) }),
+            })
+            .collect(),
+    }
+}
+
+// `<For each={signal_vec} animate={ms}>{|item| ..}</For>`
+//
+// Renders each item of a `SignalVec` with the closure, cloning whatever it captures (like event handlers). With
+// `animate`, the closure also gets an `AnimatedMapBroadcaster` to animate items in and out.
+fn generate_for_code(element: &Element) -> syn::Result<proc_macro2::TokenStream> {
+    if let Some(Spread(spread)) = element.spreads.first() {
+        return Err(syn::Error::new_spanned(spread, "`<For>` doesn't accept {..spread} props"));
+    }
+
+    let mut each = None;
+    let mut animate = None;
+    for prop in &element.props {
+        match prop.name.to_string().as_str() {
+            "each" => each = Some(&prop.value),
+            "animate" => animate = Some(&prop.value),
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &prop.name,
+                    format!("Unknown `<For>` prop `{}`, expected `each` or `animate`", prop.name),
+                ));
             }
+        }
+    }
+    let each = each.ok_or_else(|| syn::Error::new_spanned(&element.name, "`<For>` needs an `each={signal_vec}` prop"))?;
+
+    let children = element
+        .children
+        .iter()
+        .filter(|child| !matches!(child.as_ref(), Node::Text(text) if text.trim().is_empty()))
+        .collect::<Vec<_>>();
+    let closure = match children.as_slice() {
+        [child] => match child.as_ref() {
+            Node::Expression(Expr::Closure(closure)) => Some(closure),
+            _ => None,
+        },
+        _ => None,
+    };
+    let closure = closure.ok_or_else(|| {
+        syn::Error::new_spanned(&element.name, "`<For>` expects a single `{|item| ..}` closure as its child")
+    })?;
+    let mut visitor = IdentifierVisitor::with_macros();
+    visitor.visit_expr_closure(closure);
+    let clones = visitor
+        .identifiers
+        .into_iter()
+        .filter(|ident| ident != "self" && !ident.starts_with(char::is_uppercase))
+        .map(|ident| {
+            let ident = syn::Ident::new(&ident, proc_macro2::Span::call_site());
+            quote! { let #ident = #ident.clone(); }
+        })
+        .collect::<Vec<_>>();
+
+    let items = match animate {
+        Some(duration) => quote! {
+            ::rustsx::dominator::animation::AnimatedSignalVec::animated_map(
+                __each,
+                (#duration) as f64,
+                move |__item, __animation| {
+                    #(#clones)*
+                    (#closure)(__item, __animation)
+                },
+            )
+        },
+        None => quote! {
+            ::rustsx::futures_signals::signal_vec::SignalVecExt::map(__each, move |__item| {
+                #(#clones)*
+                (#closure)(__item)
+            })
+        },
+    };
+
+    Ok(quote! {
+        .children_signal_vec({
+            let __each = #each;
+            #(#clones)*
+            #items
+        })
+    })
+}
 
-            doms
+// `{if cond { .. } else { .. }}`
+//
+// Plain `bool` conditions pick a branch once, signals (or `Mutable`s) become a `child_signal` that swaps the active
+// branch in and out as the conditions change
+fn generate_if_code(if_node: &If) -> proc_macro2::TokenStream {
+    let conds = (0..if_node.branches.len()).map(|i| quote::format_ident!("__cond{}", i)).collect::<Vec<_>>();
+    let reactive = if_node.branches.iter().map(|(cond, _)| generate_reactive_code(cond));
+
+    let static_branches = if_node.branches.iter().map(|(_, nodes)| {
+        let methods = generate_children_methods(nodes);
+        quote! { dom #(#methods)* }
+    });
+    let static_otherwise = match &if_node.otherwise {
+        Some(nodes) => {
+            let methods = generate_children_methods(nodes);
+            quote! { dom #(#methods)* }
         }
-        Node::Expression(expr) => {
-            // Check if the expression already returns a signal type
-            // Look for method calls that return signals: .signal(), .signal_cloned(), .signal_ref(), .map(), etc.
-            let expr_str = quote!(#expr).to_string();
+        None => quote! { dom },
+    };
 
-            if expr_str.contains(".signal()")
-                || expr_str.contains(".signal_cloned()")
-                || expr_str.contains(".signal_ref(")
-                || expr_str.contains(".map(")
-                || expr_str.contains(".map_ref(")
-            {
-                // Expression is already a signal, just ensure it produces String
-                vec![quote! {
-                    Dom::text_signal({
-                        use futures_signals::signal::SignalExt;
-                        (#expr).map(|x| format!("{}", x))
-                    })
-                }]
-            } else {
-                // Expression is a Mutable, call .signal() on it
-                vec![quote! {
-                    Dom::text_signal({
-                        use futures_signals::signal::SignalExt;
-                        #expr.signal_cloned().map(|x| format!("{}", x))
-                    })
-                }]
+    let bodies = if_node.branches.iter().map(|(_, nodes)| nodes).chain(&if_node.otherwise).collect::<Vec<_>>();
+    let indices = (0..if_node.branches.len()).collect::<Vec<_>>();
+    let otherwise_index = if_node.branches.len();
+    let (many, branch_doms, empty) = generate_branch_doms(&bodies);
+    let (signal_branches, signal_otherwise) = match if_node.otherwise {
+        Some(_) => (&branch_doms[..indices.len()], branch_doms[indices.len()].clone()),
+        None => (&branch_doms[..], empty),
+    };
+    let clones = generate_branch_clones(bodies.iter().flat_map(|nodes| nodes.iter()), &HashSet::new());
+    let mount = mount_branches(many, quote! {
+        ::rustsx::futures_signals::signal::SignalExt::map(__branch, move |__branch| {
+            #(#clones)*
+            match __branch {
+                #(#indices => #signal_branches,)*
+                _ => #signal_otherwise,
+            }
+        })
+    });
+
+    quote! {
+        .apply(|dom| {
+            #(let #conds = #reactive;)*
+            match (#(#conds,)*) {
+                (#(::rustsx::reactive::Reactive::Value(#conds),)*) => {
+                    #(if #conds { #static_branches } else)* { #static_otherwise }
+                }
+                (#(#conds,)*) => {
+                    #(let #conds = #conds.into_signal();)*
+                    let __branch = ::rustsx::futures_signals::map_ref! {
+                        #(let #conds = #conds),* => #(if *#conds { #indices } else)* { #otherwise_index }
+                    };
+                    let __branch = ::rustsx::futures_signals::signal::SignalExt::dedupe(__branch);
+                    #(#clones)*
+                    #mount
+                }
+            }
+        })
+    }
+}
+
+// `{match expr { pat => .. }}`, with the same static / signal split as `{if ..}`
+fn generate_match_code(match_node: &Match) -> proc_macro2::TokenStream {
+    let reactive = generate_reactive_code(&match_node.expr);
+    let pats = match_node.arms.iter().map(|arm| &arm.pat).collect::<Vec<_>>();
+    let guards = match_node.arms.iter().map(|arm| arm.guard.as_ref().map(|guard| quote! { if #guard })).collect::<Vec<_>>();
+
+    let static_arms = match_node.arms.iter().map(|arm| {
+        let methods = generate_children_methods(&arm.body);
+        quote! { dom #(#methods)* }
+    });
+
+    let bodies = match_node.arms.iter().map(|arm| &arm.body).collect::<Vec<_>>();
+    let (many, arm_doms, _) = generate_branch_doms(&bodies);
+
+    // Pattern bindings come from the matched value, not the surrounding scope
+    let mut bindings = BindingVisitor(HashSet::new());
+    for pat in &pats {
+        bindings.visit_pat(pat);
+    }
+    let clones = generate_branch_clones(bodies.iter().flat_map(|nodes| nodes.iter()), &bindings.0);
+    let mount = mount_branches(many, quote! {
+        ::rustsx::futures_signals::signal::SignalExt::map(__signal, move |__value| {
+            #(#clones)*
+            match __value {
+                #(#pats #guards => #arm_doms,)*
+            }
+        })
+    });
+
+    quote! {
+        .apply(|dom| match #reactive {
+            ::rustsx::reactive::Reactive::Value(__value) => match __value {
+                #(#pats #guards => #static_arms,)*
+            },
+            ::rustsx::reactive::Reactive::Signal(__signal) => {
+                #(#clones)*
+                #mount
+            }
+        })
+    }
+}
+
+// Each branch as an `Option<Dom>` for `child_signal`, or as a `Vec<Dom>` for `children_signal_vec` if any branch has
+// more than one node (the `bool`). Also returns an empty branch.
+fn generate_branch_doms(bodies: &[&Vec<Node>]) -> (bool, Vec<proc_macro2::TokenStream>, proc_macro2::TokenStream) {
+    let doms = bodies.iter().map(|nodes| nodes.iter().flat_map(generate_child_code).collect::<Vec<_>>()).collect::<Vec<_>>();
+
+    if doms.iter().all(|doms| matches!(doms.as_slice(), [] | [DomCode::One(_)])) {
+        let branches = doms
+            .into_iter()
+            .map(|doms| match doms.into_iter().next() {
+                Some(DomCode::One(dom) | DomCode::Many(dom)) => quote! { ::core::option::Option::Some(#dom) },
+                None => quote! { ::core::option::Option::None },
+            })
+            .collect();
+        (false, branches, quote! { ::core::option::Option::None })
+    } else {
+        let branches = doms.into_iter().map(collect_doms).collect();
+        (true, branches, quote! { ::std::vec::Vec::new() })
+    }
+}
+
+// Mounts a signal of branches from `generate_branch_doms` onto `dom`
+fn mount_branches(many: bool, signal: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    if many {
+        quote! { dom.children_signal_vec(::rustsx::futures_signals::signal::SignalExt::to_signal_vec(#signal)) }
+    } else {
+        quote! { dom.child_signal(#signal) }
+    }
+}
+
+// Shadowing clones for every local some nodes use, for when they're rebuilt each time a signal changes
+fn generate_branch_clones<'a>(
+    nodes: impl IntoIterator<Item = &'a Node>,
+    skip: &HashSet<String>,
+) -> Vec<proc_macro2::TokenStream> {
+    let mut visitor = IdentifierVisitor::with_macros();
+    for node in nodes {
+        visit_node(&mut visitor, node);
+    }
+
+    let mut idents = visitor
+        .identifiers
+        .into_iter()
+        .filter(|ident| !skip.contains(ident) && ident != "self" && !ident.starts_with(char::is_uppercase))
+        .collect::<Vec<_>>();
+    idents.sort();
+
+    idents
+        .into_iter()
+        .map(|ident| {
+            let ident = syn::Ident::new(&ident, proc_macro2::Span::call_site());
+            quote! { let #ident = #ident.clone(); }
+        })
+        .collect()
+}
+
+fn visit_node(visitor: &mut IdentifierVisitor, node: &Node) {
+    match node {
+        Node::Element(element) => {
+            for prop in &element.props {
+                match &prop.style {
+                    Some(style) => style.iter().for_each(|property| visitor.visit_expr(&property.value)),
+                    None => visitor.visit_expr(&prop.value),
+                }
+            }
+            for Spread(spread) in &element.spreads {
+                visitor.visit_expr(spread);
+            }
+            for child in &element.children {
+                visit_node(visitor, child);
             }
         }
+        Node::Fragment(fragment) => fragment.children.iter().for_each(|child| visit_node(visitor, child)),
+        Node::Text(_) => {}
+        Node::Expression(expr) => visitor.visit_expr(expr),
+        Node::If(if_node) => {
+            for (cond, nodes) in &if_node.branches {
+                visitor.visit_expr(cond);
+                nodes.iter().for_each(|child| visit_node(visitor, child));
+            }
+            if let Some(nodes) = &if_node.otherwise {
+                nodes.iter().for_each(|child| visit_node(visitor, child));
+            }
+        }
+        Node::Match(match_node) => {
+            visitor.visit_expr(&match_node.expr);
+            for arm in &match_node.arms {
+                if let Some(guard) = &arm.guard {
+                    visitor.visit_expr(guard);
+                }
+                arm.body.iter().for_each(|child| visit_node(visitor, child));
+            }
+        }
+    }
+}
+
+// Collects the names bound by a pattern
+struct BindingVisitor(HashSet<String>);
+
+impl<'ast> Visit<'ast> for BindingVisitor {
+    fn visit_pat_ident(&mut self, node: &'ast syn::PatIdent) {
+        self.0.insert(node.ident.to_string());
+        syn::visit::visit_pat_ident(self, node);
     }
 }
 
 #[proc_macro]
 pub fn rsx(input: TokenStream) -> TokenStream {
-    let input2: proc_macro2::TokenStream = input.into();
-    if let Ok(element) = syn::parse2::<Element>(input2) {
-        let dom_code = generate_dom_code(&element);
-        TokenStream::from(dom_code)
-    } else {
-        syn::Error::new(
-            proc_macro2::Span::call_site(),
-            "Invalid JSX syntax - make sure to use proper HTML-like syntax: rsx!(<tag>content</tag>)",
-        )
-        .to_compile_error()
-        .into()
-    }
+    let Nodes(nodes) = match syn::parse::<Nodes>(input) {
+        Ok(nodes) => nodes,
+        Err(errors) => return generate_errors(errors),
+    };
+
+    let warnings = match rsx_parser::validate::validate(&nodes) {
+        Ok(warnings) if cfg!(feature = "warnings") => warnings.iter().map(generate_warning).collect::<Vec<_>>(),
+        Ok(_) => Vec::new(),
+        Err(errors) => return generate_errors(errors),
+    };
+
+    let dom_code = match nodes.as_slice() {
+        // A single root element is returned as-is
+        [Node::Element(element)] => generate_dom_code(element),
+        // Fragments and multiple roots are returned as a `Vec<Dom>`
+        _ => {
+            let doms = nodes.iter().flat_map(generate_child_code).collect::<Vec<_>>();
+            collect_doms(doms)
+        }
+    };
+
+    TokenStream::from(match warnings.is_empty() {
+        true => dom_code,
+        false => quote! {{ #(#warnings)* #dom_code }},
+    })
+}
+
+// Combined errors are one `compile_error!` each, so they're wrapped in a block to still be a single expression
+fn generate_errors(errors: syn::Error) -> TokenStream {
+    let errors = errors.to_compile_error();
+    TokenStream::from(quote! {{ #errors }})
+}
+
+// Proc macros can't emit warnings on stable, so use of a deprecated const stands in for one, e.g.
+// "use of deprecated constant `page::rsx_unknown_attribute`: Unknown attribute `clas` on `<div>`, did you mean `class`?".
+// The const is named after the kind so each is recognisable in the output. Legacy code can opt out with
+// `#[allow(deprecated)]` on the function or module, or everywhere by turning off rustsx's `warnings` feature.
+fn generate_warning(warning: &rsx_parser::validate::Warning) -> proc_macro2::TokenStream {
+    let message = &warning.message;
+    let kind = quote::format_ident!("rsx_{}", warning.kind, span = warning.span);
+    quote::quote_spanned! {warning.span=> {
+        #[deprecated(note = #message)]
+        #[allow(non_upper_case_globals)]
+        const #kind: () = ();
+        let () = #kind;
+    }}
 }
 
 #[proc_macro_attribute]
 pub fn component(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    let input = syn::parse_macro_input!(item as syn::ItemFn);
+    let mut input = syn::parse_macro_input!(item as syn::ItemFn);
 
     // Extract function name and convert to PascalCase for component and props struct names
     let fn_name = &input.sig.ident;
     let fn_name_str = fn_name.to_string();
     let component_name = syn::Ident::new(&fn_name_str.to_pascal_case(), fn_name.span());
     let props_struct_name = syn::Ident::new(&format!("{}Props", fn_name_str.to_pascal_case()), fn_name.span());
+    let builder_name = syn::Ident::new(&format!("{}PropsBuilder", fn_name_str.to_pascal_case()), fn_name.span());
 
     // Extract parameters and generate props struct fields
-    let mut prop_fields = Vec::new();
-    let mut fn_params = Vec::new();
-    let mut original_params = Vec::new();
-    let mut generic_params = Vec::new();
-    let mut where_clauses = Vec::new();
+    let mut props = Vec::new();
 
-    for param in &input.sig.inputs {
+    for param in &mut input.sig.inputs {
         if let syn::FnArg::Typed(pat_type) = param {
+            // `#[prop(..)]` and doc comments are only meaningful to us, the impl function can't keep them
+            let mut default = None;
+            let mut docs = Vec::new();
+            let mut attr_error = None;
+            pat_type.attrs.retain(|attr| {
+                if attr.path().is_ident("doc") {
+                    docs.push(attr.clone());
+                    return false;
+                }
+                if !attr.path().is_ident("prop") {
+                    return true;
+                }
+                if let Err(e) = parse_prop_attr(attr, &mut default) {
+                    attr_error = Some(e);
+                }
+                false
+            });
+            if let Some(e) = attr_error {
+                return e.to_compile_error().into();
+            }
+
             // Extract parameter name
             let param_name = if let syn::Pat::Ident(pat_ident) = &*pat_type.pat {
-                &pat_ident.ident
+                pat_ident.ident.clone()
             } else {
                 continue;
             };
 
-            // Check if this is a Mutable<T> parameter
-            if is_mutable_type(&pat_type.ty) {
-                let inner_type = extract_inner_type(&pat_type.ty);
-                let generic_name =
-                    syn::Ident::new(&format!("__{}", param_name.to_string().to_uppercase()), param_name.span());
-
-                // Add generic parameter for the prop type
-                generic_params.push(quote! { #generic_name });
-
-                // Add where clause to constrain the generic
-                where_clauses.push(quote! {
-                    #generic_name: Into<::futures_signals::signal::Mutable<#inner_type>>
-                });
-
-                // Use the generic type in the props field
-                prop_fields.push(quote! {
-                    pub #param_name: #generic_name
-                });
-
-                // Convert the prop value to Mutable<T>
-                fn_params.push(quote! { props.#param_name.into() });
-            } else {
-                // For non-Mutable types, pass through directly
-                let field_type = &pat_type.ty;
-                prop_fields.push(quote! {
-                    pub #param_name: #field_type
-                });
-
-                fn_params.push(quote! { props.#param_name });
+            // `Option<T>` and `children` can always be left out
+            if default.is_none() && (is_option_type(&pat_type.ty) || param_name == "children") {
+                default = Some(PropDefault::Default);
             }
 
-            // Keep original parameters for the impl function
-            original_params.push(pat_type);
+            props.push(ComponentProp { name: param_name, ty: (*pat_type.ty).clone(), docs, default });
         }
     }
 
     let fn_vis = &input.vis;
     let fn_block = &input.block;
     let fn_return = &input.sig.output;
+    let original_params = &input.sig.inputs;
+
+    // The wrapper gets every attribute, the impl everything but docs and the other items just the `#[cfg]`s
+    let fn_attrs = &input.attrs;
+    let impl_attrs = input.attrs.iter().filter(|attr| !attr.path().is_ident("doc")).collect::<Vec<_>>();
+    let cfg_attrs = input.attrs.iter().filter(|attr| attr.path().is_ident("cfg")).collect::<Vec<_>>();
+    let props_doc = format!("Props for [`{}`]", component_name);
+
+    // The component's own generics, shared by the props struct, its builder and both functions
+    let generics = &input.sig.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let generic_args = generics
+        .params
+        .iter()
+        .map(|param| match param {
+            syn::GenericParam::Lifetime(param) => {
+                let lifetime = &param.lifetime;
+                quote! { #lifetime }
+            }
+            syn::GenericParam::Type(param) => {
+                let ident = &param.ident;
+                quote! { #ident }
+            }
+            syn::GenericParam::Const(param) => {
+                let ident = &param.ident;
+                quote! { #ident }
+            }
+        })
+        .collect::<Vec<_>>();
+    let generic_params = generics.params.iter().collect::<Vec<_>>();
+
+    // Required props only live in the builder's state types, so it needs a marker for the component's generics
+    let phantom = generics.params.iter().filter_map(|param| match param {
+        syn::GenericParam::Lifetime(param) => {
+            let lifetime = &param.lifetime;
+            Some(quote! { &#lifetime () })
+        }
+        syn::GenericParam::Type(param) => {
+            let ident = &param.ident;
+            Some(quote! { fn() -> #ident })
+        }
+        syn::GenericParam::Const(_) => None,
+    });
+    let phantom = quote! { ::core::marker::PhantomData<(#(#phantom,)*)> };
 
     // Create a wrapper function that takes props and calls the impl
     let impl_fn_name = syn::Ident::new(&format!("{}_impl", fn_name), fn_name.span());
 
-    // Generate the props struct with generics if needed
-    let (props_generics, where_clause) = if !generic_params.is_empty() {
-        (quote! { <#(#generic_params),*> }, quote! { where #(#where_clauses),* })
-    } else {
-        (quote! {}, quote! {})
-    };
+    let names = props.iter().map(|prop| &prop.name).collect::<Vec<_>>();
+    let fields = props.iter().map(|prop| {
+        let ComponentProp { name, ty, docs, .. } = prop;
+        quote! { #(#docs)* pub #name: #ty }
+    });
+
+    // Required props are tracked in the builder's type (`Missing` / `Set<T>`), so leaving one out fails to compile
+    // with a message naming it. Everything else is an `Option` filled in by `build()`.
+    let required = props.iter().filter(|prop| prop.default.is_none()).collect::<Vec<_>>();
+    let states = required
+        .iter()
+        .map(|prop| syn::Ident::new(&format!("__{}", prop.name.to_string().to_uppercase()), prop.name.span()))
+        .collect::<Vec<_>>();
+    let checks = required
+        .iter()
+        .map(|prop| syn::Ident::new(&format!("__{}Has_{}", props_struct_name, prop.name), prop.name.span()))
+        .collect::<Vec<_>>();
+    let check_traits = required.iter().zip(&checks).map(|(prop, check)| {
+        let message = format!("`<{}>` is missing the required prop `{}`", component_name, prop.name);
+        let label = format!("add `{}={{{{..}}}}` here", prop.name);
+        quote! {
+            #(#cfg_attrs)*
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            #[diagnostic::on_unimplemented(message = #message, label = #label)]
+            #fn_vis trait #check<T> {
+                fn take(self) -> T;
+            }
+
+            #(#cfg_attrs)*
+            impl<T> #check<T> for ::rustsx::component::Set<T> {
+                #[inline]
+                fn take(self) -> T { self.0 }
+            }
+        }
+    });
+    let required_types = required.iter().map(|prop| &prop.ty).collect::<Vec<_>>();
+    let missing = required.iter().map(|_| quote! { ::rustsx::component::Missing });
+
+    let builder_fields = props.iter().map(|prop| {
+        let name = &prop.name;
+        let ty = &prop.ty;
+        match required.iter().position(|required| required.name == prop.name) {
+            Some(i) => {
+                let state = &states[i];
+                quote! { #name: #state }
+            }
+            None => quote! { #name: ::core::option::Option<#ty> },
+        }
+    });
+    let empty_fields = props.iter().map(|prop| match prop.default {
+        Some(_) => quote! { ::core::option::Option::None },
+        None => quote! { ::rustsx::component::Missing },
+    });
+    let full_fields = props.iter().map(|prop| {
+        let name = &prop.name;
+        match prop.default {
+            Some(_) => quote! { ::core::option::Option::Some(self.#name) },
+            None => quote! { ::rustsx::component::Set(self.#name) },
+        }
+    });
+
+    let setters = props.iter().map(|prop| {
+        let name = &prop.name;
+        let ty = &prop.ty;
+        let docs = &prop.docs;
+
+        match required.iter().position(|required| required.name == prop.name) {
+            Some(i) => {
+                let next_states = states.iter().enumerate().map(|(j, state)| {
+                    if i == j {
+                        quote! { ::rustsx::component::Set<#ty> }
+                    } else {
+                        quote! { #state }
+                    }
+                });
+                let moved = names.iter().map(|other| {
+                    if *other == name {
+                        quote! { #other: ::rustsx::component::Set(#other) }
+                    } else {
+                        quote! { #other: self.#other }
+                    }
+                });
+                quote! {
+                    #(#docs)*
+                    pub fn #name(self, #name: #ty) -> #builder_name<#(#generic_args,)* #(#next_states),*> {
+                        #builder_name { #(#moved,)* __marker: ::core::marker::PhantomData }
+                    }
+                }
+            }
+            None => quote! {
+                #(#docs)*
+                pub fn #name(mut self, #name: #ty) -> Self {
+                    self.#name = ::core::option::Option::Some(#name);
+                    self
+                }
+            },
+        }
+    });
+
+    let built_fields = props.iter().map(|prop| {
+        let name = &prop.name;
+        match &prop.default {
+            Some(PropDefault::Default) => quote! { #name: self.#name.unwrap_or_default() },
+            Some(PropDefault::Expr(expr)) => quote! { #name: self.#name.unwrap_or_else(|| #expr) },
+            None => {
+                let i = required.iter().position(|required| required.name == prop.name).unwrap();
+                let check = &checks[i];
+                quote! { #name: #check::take(self.#name) }
+            }
+        }
+    });
+
+    // A `children` parameter takes nested markup, see `generate_component_code`
+    let children_impl = names.iter().any(|name| *name == "children").then(|| {
+        quote! {
+            #(#cfg_attrs)*
+            impl<#(#generic_params,)* #(#states),*> ::rustsx::component::AcceptsChildren
+                for #builder_name<#(#generic_args,)* #(#states),*>
+            #where_clause
+            {
+                #[inline]
+                fn children(self, children: ::rustsx::component::Children) -> Self { self.children(children) }
+            }
+        }
+    });
 
     let expanded = quote! {
+        #(#cfg_attrs)*
+        #[doc = #props_doc]
         #[derive(Clone)]
         #[allow(non_snake_case)]
-        #fn_vis struct #props_struct_name #props_generics
-        #where_clause
-        {
-            #(#prop_fields),*
+        #fn_vis struct #props_struct_name #impl_generics #where_clause {
+            #(#fields),*
         }
 
+        #(#cfg_attrs)*
+        #[doc(hidden)]
         #[allow(non_snake_case)]
-        #fn_vis fn #component_name #props_generics (props: #props_struct_name #props_generics) #fn_return
-        #where_clause
-        {
-            #impl_fn_name(#(#fn_params),*)
+        #fn_vis struct #builder_name<#(#generic_params,)* #(#states),*> #where_clause {
+            #(#builder_fields,)*
+            __marker: #phantom,
         }
 
+        #(#check_traits)*
+
+        #(#cfg_attrs)*
+        impl #impl_generics #props_struct_name #ty_generics #where_clause {
+            /// Sets props one at a time, leaving out any optional ones, as `rsx!` does
+            pub fn builder() -> #builder_name<#(#generic_args,)* #(#missing),*> {
+                #builder_name { #(#names: #empty_fields,)* __marker: ::core::marker::PhantomData }
+            }
+
+            /// A builder with every prop already set, as used by `{..props}` spreads
+            pub fn into_builder(self) -> #builder_name<#(#generic_args,)* #(::rustsx::component::Set<#required_types>),*> {
+                #builder_name { #(#names: #full_fields,)* __marker: ::core::marker::PhantomData }
+            }
+        }
+
+        #(#cfg_attrs)*
         #[allow(non_snake_case)]
-        fn #impl_fn_name(#(#original_params),*) #fn_return {
+        impl<#(#generic_params,)* #(#states),*> #builder_name<#(#generic_args,)* #(#states),*> #where_clause {
+            #(#setters)*
+
+            pub fn build(self) -> #props_struct_name #ty_generics
+            where
+                #(#states: #checks<#required_types>),*
+            {
+                #props_struct_name { #(#built_fields),* }
+            }
+        }
+
+        #children_impl
+
+        #(#fn_attrs)*
+        #[allow(non_snake_case)]
+        #fn_vis fn #component_name #impl_generics (props: #props_struct_name #ty_generics) #fn_return #where_clause {
+            #impl_fn_name(#(props.#names),*)
+        }
+
+        #(#impl_attrs)*
+        #[allow(non_snake_case)]
+        fn #impl_fn_name #impl_generics (#original_params) #fn_return #where_clause {
             #fn_block
         }
     };
@@ -535,28 +1473,39 @@ pub fn component(_attr: TokenStream, item: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
-// Helper function to check if a type is Mutable<T>
-fn is_mutable_type(ty: &syn::Type) -> bool {
-    if let syn::Type::Path(type_path) = ty
-        && let Some(segment) = type_path.path.segments.last()
-    {
-        return segment.ident == "Mutable";
-    }
-    false
+struct ComponentProp {
+    name: syn::Ident,
+    ty: syn::Type,
+    docs: Vec<syn::Attribute>,
+    default: Option<PropDefault>,
+}
+
+// `#[prop(default)]` or `#[prop(default = expr)]`
+enum PropDefault {
+    Default,
+    Expr(Expr),
+}
+
+fn parse_prop_attr(attr: &syn::Attribute, default: &mut Option<PropDefault>) -> syn::Result<()> {
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("default") {
+            *default = Some(match meta.value() {
+                Ok(value) => PropDefault::Expr(value.parse()?),
+                Err(_) => PropDefault::Default,
+            });
+            Ok(())
+        } else {
+            Err(meta.error("Unknown prop option, expected `default` or `default = expr`"))
+        }
+    })
 }
 
-// Helper function to extract T from Mutable<T> or return the type as-is
-fn extract_inner_type(ty: &syn::Type) -> syn::Type {
+// Helper function to check if a type is Option<T>
+fn is_option_type(ty: &syn::Type) -> bool {
     if let syn::Type::Path(type_path) = ty
         && let Some(segment) = type_path.path.segments.last()
     {
-        // Check if this is Mutable<T>
-        if segment.ident == "Mutable"
-            && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
-            && let Some(syn::GenericArgument::Type(inner_ty)) = args.args.first()
-        {
-            return inner_ty.clone();
-        }
+        return segment.ident == "Option";
     }
-    ty.clone()
+    false
 }
@@ -20,21 +20,9 @@ fn generate_attr_map() {
     let json_path = "mdn/attributes.json";
     let json_content = fs::read_to_string(json_path).expect("Failed to read attributes.json");
 
-    // Execute the transformation using Node.js
-    let node_script = format!(
-        "const attrs = {json_content}; const result = attrs.map(v => `(\"${{v.attr}}\", &[${{v.tags.map(v => `\"${{v}}\"`).join(\", \
-         \")}}])`); console.log(result.join(',\\n'));"
-    );
-
-    let output = Command::new("node").args(["-e", &node_script]).output().expect("Failed to execute Node.js");
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        panic!("Node.js execution failed: {stderr}");
-    }
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let generated_entries = stdout.trim();
+    let generated_entries = attr_entries(&json_content, "true", "");
+    let deprecated_entries = attr_entries(&json_content, "v.depreciated", "");
+    let type_entries = attr_entries(&json_content, "true", ATTR_TYPE);
 
     // Generate the Rust code
     let rust_code = format!(
@@ -42,6 +30,16 @@ fn generate_attr_map() {
 pub const ATTR_MAP: &[(&str, &[&str])] = &[
     {generated_entries}
 ];
+
+/// Generated map of deprecated attributes from MDN data
+pub const DEPRECATED_ATTR_MAP: &[(&str, &[&str])] = &[
+    {deprecated_entries}
+];
+
+/// Generated map of attribute value types from MDN data
+pub const ATTR_TYPES: &[(&str, &[&str], crate::attr_types::AttrType)] = &[
+    {type_entries}
+];
 "#
     );
 
@@ -50,3 +48,35 @@ pub const ATTR_MAP: &[(&str, &[&str])] = &[
 
     println!("Generated attribute map at: {}", dest_path.display());
 }
+
+// JS turning an attribute's `type` into an `AttrType`, e.g. `NUMBER` into `Number` or `(GET | POST)` into
+// `Enum(&["get", "post"])`. Single-value enumerations such as `(checked)` are boolean attributes.
+const ATTR_TYPE: &str = r#"", crate::attr_types::AttrType::" + (
+    v.type === "NUMBER" || v.type === "%Pixels;" ? "Number"
+    : v.type === "%URI;" ? "Url"
+    : v.type === "%FrameTarget;" ? "Target"
+    : /^\([\w-]+\)$/.test(v.type) ? "Boolean"
+    : /^\(.*\)$/.test(v.type) ? "Enum(&[" + v.type.slice(1, -1).split("|").map(v => `"${v.trim().toLowerCase()}"`).join(", ") + "])"
+    : "Text"
+)"#;
+
+// `("attr", &["tag", ..])` for each attribute matching `filter`, a JS expression of `v`, followed by `extra` (a JS
+// string expression of `v`) if given
+fn attr_entries(json_content: &str, filter: &str, extra: &str) -> String {
+    let extra = if extra.is_empty() { "\"\"" } else { extra };
+
+    // Execute the transformation using Node.js
+    let node_script = format!(
+        "const attrs = {json_content}; const result = attrs.filter(v => {filter}).map(v => `(\"${{v.attr}}\", &[${{v.tags.map(v => `\"${{v}}\"`).join(\", \
+         \")}}]${{{extra}}})`); console.log(result.join(',\\n'));"
+    );
+
+    let output = Command::new("node").args(["-e", &node_script]).output().expect("Failed to execute Node.js");
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        panic!("Node.js execution failed: {stderr}");
+    }
+
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
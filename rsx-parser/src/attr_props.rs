@@ -1,43 +1,129 @@
 use {
+    crate::validate::is_known_attr,
     futures_signals::signal::{Signal, SignalExt},
     itertools::Itertools,
+    js_sys::Reflect,
     rsx_dominator::DomBuilder,
-    std::ops::Not,
-    web_sys::{Element, HtmlElement, wasm_bindgen::JsValue},
+    std::future::ready,
+    web_sys::{
+        Element,
+        wasm_bindgen::{JsCast, JsValue},
+    },
 };
 
-pub fn apply<A: AsRef<HtmlElement> + AsRef<Element> + AsRef<JsValue>, B: Into<String> + Clone, C: Into<JsValue> + Clone>(
+/// Sets `key` on an element as a DOM property or an attribute, whichever the element expects (see
+/// [`should_set_as_prop`]). `undefined` / `null` (e.g. from `None`) leave it off either way.
+pub fn apply<A: AsRef<Element> + AsRef<JsValue>, B: Into<String>, C: Into<JsValue>>(
     el: DomBuilder<A>,
     key: B,
     value: C,
 ) -> DomBuilder<A> {
-    match should_set_as_prop(&el, key.clone(), value.clone()) {
-        true => el.prop(key.into(), value),
-        false => el.attr(key.into(), &Into::<JsValue>::into(value).as_string().unwrap_or_default()),
+    let key = key.into();
+    let value = value.into();
+    if value.is_undefined() || value.is_null() {
+        return el;
+    }
+    match should_set_as_prop(&el, &key, &value) {
+        true => el.prop(key, value),
+        false => match attr_value(value) {
+            Some(value) => el.attr(key, &value),
+            None => el,
+        },
     }
 }
 
-pub fn bind<
-    A: AsRef<HtmlElement> + AsRef<Element> + AsRef<JsValue>,
-    B: Into<String> + Clone,
-    C: Into<JsValue>,
-    D: Signal<Item = C> + 'static,
->(
+/// Like [`apply`], but follows a signal. The attribute is removed while the signal is `undefined` / `null`, also for
+/// keys that are otherwise set as properties, as setting those to `undefined` would show the text "undefined".
+pub fn bind<A: AsRef<Element> + AsRef<JsValue>, B: Into<String>, C: Into<JsValue>, D: Signal<Item = C> + 'static>(
     el: DomBuilder<A>,
     key: B,
     value: D,
 ) -> DomBuilder<A> {
-    match should_set_as_prop(&el, key.clone(), JsValue::undefined()) {
-        true => el.prop_signal(key.into(), value),
-        false => el.attr_signal(key.into(), value.map(|v| Into::<JsValue>::into(v).as_string().unwrap_or_default())),
+    let key = key.into();
+    let element: Element = AsRef::<Element>::as_ref(&el).clone();
+    el.future(value.for_each(move |value| {
+        let value = value.into();
+        if value.is_undefined() || value.is_null() {
+            let _ = element.remove_attribute(&key);
+        } else if should_set_as_prop(&element, &key, &value) {
+            let _ = Reflect::set(&element, &JsValue::from_str(&key), &value);
+        } else if let Some(value) = attr_value(value) {
+            let _ = element.set_attribute(&key, &value);
+        }
+        ready(())
+    }))
+}
+
+/// The text of an attribute, or `None` to leave it off (`undefined` / `null`, e.g. from an `Option`)
+pub fn attr_value(value: JsValue) -> Option<String> {
+    if value.is_undefined() || value.is_null() {
+        return None;
+    }
+
+    value
+        .as_string()
+        .or_else(|| value.as_f64().map(|v| v.to_string()))
+        .or_else(|| value.as_bool().map(|v| v.to_string()))
+        // 64 and 128 bit integers become `BigInt`s
+        .or_else(|| value.dyn_ref::<js_sys::BigInt>().and_then(|v| v.to_string(10).ok()).map(String::from))
+}
+
+/// Attributes whose presence (rather than value) is meaningful, as listed in the HTML spec
+/// https://html.spec.whatwg.org/multipage/indices.html#attributes-3
+pub const BOOLEAN_ATTRS: &[&str] = &[
+    "allowfullscreen",
+    "async",
+    "autofocus",
+    "autoplay",
+    "checked",
+    "controls",
+    "default",
+    "defer",
+    "disabled",
+    "formnovalidate",
+    "hidden",
+    "inert",
+    "ismap",
+    "itemscope",
+    "loop",
+    "multiple",
+    "muted",
+    "nomodule",
+    "novalidate",
+    "open",
+    "playsinline",
+    "readonly",
+    "required",
+    "reversed",
+    "selected",
+];
+
+pub fn is_boolean_attr(key: &str) -> bool { BOOLEAN_ATTRS.contains(&key) }
+
+/// Namespace URI for a prefixed attribute name like `xlink:href`
+/// https://infra.spec.whatwg.org/#namespaces
+pub fn namespace_uri(prefix: &str) -> Option<&'static str> {
+    match prefix {
+        "xlink" => Some("http://www.w3.org/1999/xlink"),
+        "xml" => Some("http://www.w3.org/XML/1998/namespace"),
+        "xmlns" => Some("http://www.w3.org/2000/xmlns/"),
+        _ => None,
     }
 }
 
 mod attrs {
     include!(concat!(env!("OUT_DIR"), "/generated_attrs.rs"));
-    pub const MAP: &[(&str, &[&str])] = ATTR_MAP;
 }
 
+/// Attributes and the tags they apply to, from `mdn/attributes.json`
+pub use attrs::ATTR_MAP;
+/// Attributes and the tags they're deprecated on, from `mdn/attributes.json`
+pub use attrs::DEPRECATED_ATTR_MAP;
+/// Attributes, the tags they apply to and their value type, from `mdn/attributes.json`
+pub use attrs::ATTR_TYPES;
+
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+
 fn is_native_on(key: impl Into<String>) -> bool {
     if let Some((o, n, x)) = key.into().chars().take(3).collect_tuple() {
         o == 'o' && n == 'n' && x.is_lowercase()
@@ -46,12 +132,20 @@ fn is_native_on(key: impl Into<String>) -> bool {
     }
 }
 
+/// Whether `key` is set as a DOM property (`el[key] = value`) rather than with `setAttribute`.
+///
+/// Properties follow the element's live state, e.g. `value`, `checked`, `selected`, `muted` or `indeterminate`,
+/// which their attributes only set the initial state of. Other attributes in the MDN data stay attributes (see
+/// [`mdn_prop`]), and keys neither knows are properties if the element has one, e.g. `innerHTML`.
 // https://github.com/vuejs/core/blob/958286e3f050dc707ad1af293e91bfb190bdb191/packages/runtime-dom/src/patchProp.ts#L69
-fn should_set_as_prop<A: AsRef<HtmlElement>, B: Into<String>, C: Into<JsValue>>(el: &A, key: B, value: C) -> bool {
-    // TODO: Handle is_svg
-    let el: &HtmlElement = el.as_ref();
-    let key: &str = &key.into();
-    let value: JsValue = value.into();
+pub fn should_set_as_prop<A: AsRef<Element>>(el: &A, key: &str, value: &JsValue) -> bool {
+    let el: &Element = el.as_ref();
+
+    // most keys must be set as attribute on svg elements to work
+    // ...except innerHTML & textContent
+    if el.namespace_uri().as_deref() == Some(SVG_NAMESPACE) {
+        return key == "innerHTML" || key == "textContent";
+    }
 
     // these are enumerated attrs, however their corresponding DOM properties
     // are actually booleans - this leads to setting it with a string "false"
@@ -92,5 +186,73 @@ fn should_set_as_prop<A: AsRef<HtmlElement>, B: Into<String>, C: Into<JsValue>>(
         return false;
     }
 
-    attrs::MAP.iter().find(|v| v.0 == key).is_some_and(|v| v.1.contains(&el.tag_name().to_lowercase().as_str())).not()
+    // `Reflect::has` alone would set reflected attributes such as `href`, `title` or `id` as properties too
+    mdn_prop(&el.local_name(), key).unwrap_or_else(|| Reflect::has(el, &JsValue::from_str(key)).unwrap_or(false))
+}
+
+/// DOM properties that follow an element's live state, which their attribute only sets the initial value of
+pub const LIVE_PROPS: &[(&str, &[&str])] = &[
+    ("value", &["input", "select", "textarea"]),
+    ("checked", &["input"]),
+    ("indeterminate", &["input"]),
+    ("selected", &["option"]),
+    ("muted", &["audio", "video"]),
+];
+
+/// Whether `key` on a `tag` element is set as a DOM property (`Some(true)`, see [`LIVE_PROPS`]) or an attribute
+/// (`Some(false)`, any other attribute in the MDN data). `None` if neither knows it, e.g. `innerHTML` or the props of
+/// a custom element.
+pub fn mdn_prop(tag: &str, key: &str) -> Option<bool> {
+    if LIVE_PROPS.iter().any(|(name, tags)| *name == key && tags.contains(&tag)) {
+        Some(true)
+    } else if is_known_attr(tag, key) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn boolean_attrs() {
+        assert!(is_boolean_attr("disabled"));
+        assert!(is_boolean_attr("checked"));
+        assert!(!is_boolean_attr("title"));
+        assert!(!is_boolean_attr("draggable"));
+    }
+
+    #[test]
+    fn reflected_attributes_stay_attributes() {
+        assert_eq!(mdn_prop("a", "href"), Some(false));
+        assert_eq!(mdn_prop("a", "title"), Some(false));
+        assert_eq!(mdn_prop("img", "src"), Some(false));
+        assert_eq!(mdn_prop("div", "id"), Some(false));
+        assert_eq!(mdn_prop("button", "disabled"), Some(false));
+        assert_eq!(mdn_prop("li", "value"), Some(false));
+    }
+
+    #[test]
+    fn live_state_is_a_prop() {
+        assert_eq!(mdn_prop("input", "value"), Some(true));
+        assert_eq!(mdn_prop("textarea", "value"), Some(true));
+        assert_eq!(mdn_prop("input", "checked"), Some(true));
+        assert_eq!(mdn_prop("option", "selected"), Some(true));
+        assert_eq!(mdn_prop("video", "muted"), Some(true));
+    }
+
+    #[test]
+    fn unknown_keys_are_left_to_the_element() {
+        assert_eq!(mdn_prop("div", "innerHTML"), None);
+        assert_eq!(mdn_prop("my-element", "items"), None);
+    }
+
+    #[test]
+    fn namespaces() {
+        assert_eq!(namespace_uri("xlink"), Some("http://www.w3.org/1999/xlink"));
+        assert_eq!(namespace_uri("xml"), Some("http://www.w3.org/XML/1998/namespace"));
+        assert_eq!(namespace_uri("foo"), None);
+    }
 }
@@ -0,0 +1,186 @@
+use crate::attr_props::{ATTR_TYPES, is_boolean_attr};
+
+/// What an attribute's value should be, from the `type` of each attribute in `mdn/attributes.json`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttrType {
+    /// Free text, e.g. `title` or `alt`
+    Text,
+    /// A number, e.g. `colspan` or `maxlength`
+    Number,
+    /// A URL, e.g. `href` or `src`
+    Url,
+    /// One of a fixed set of keywords, compared case-insensitively, e.g. `method`
+    Enum(&'static [&'static str]),
+    /// Where to open a link, a browsing context's name or one of the `_blank`, `_self`, `_parent` and `_top` keywords
+    Target,
+    /// Present or not, e.g. `checked`
+    Boolean,
+}
+
+/// The keywords `target` can be, besides the name of a browsing context
+pub const TARGET_KEYWORDS: &[&str] = &["_blank", "_self", "_parent", "_top"];
+
+const REFERRER_POLICIES: &[&str] = &[
+    "",
+    "no-referrer",
+    "no-referrer-when-downgrade",
+    "origin",
+    "origin-when-cross-origin",
+    "same-origin",
+    "strict-origin",
+    "strict-origin-when-cross-origin",
+    "unsafe-url",
+];
+
+/// Types of attributes added or changed since the HTML 4 data in `mdn/attributes.json`, which take precedence over it.
+/// An empty tag list applies to every element.
+/// https://html.spec.whatwg.org/multipage/indices.html#attributes-3
+pub const HTML5_ATTR_TYPES: &[(&str, &[&str], AttrType)] = &[
+    ("action", &["form"], AttrType::Url),
+    ("autocapitalize", &[], AttrType::Enum(&["off", "none", "on", "sentences", "words", "characters"])),
+    ("contenteditable", &[], AttrType::Enum(&["", "true", "false", "plaintext-only"])),
+    ("crossorigin", &[], AttrType::Enum(&["", "anonymous", "use-credentials"])),
+    ("decoding", &["img"], AttrType::Enum(&["sync", "async", "auto"])),
+    ("dir", &[], AttrType::Enum(&["ltr", "rtl", "auto"])),
+    ("draggable", &[], AttrType::Enum(&["true", "false"])),
+    ("enterkeyhint", &[], AttrType::Enum(&["enter", "done", "go", "next", "previous", "search", "send"])),
+    ("fetchpriority", &[], AttrType::Enum(&["high", "low", "auto"])),
+    ("formaction", &["button", "input"], AttrType::Url),
+    ("formmethod", &["button", "input"], AttrType::Enum(&["get", "post", "dialog"])),
+    ("formtarget", &["button", "input"], AttrType::Target),
+    (
+        "height",
+        &["canvas", "embed", "iframe", "img", "input", "object", "source", "video"],
+        AttrType::Number,
+    ),
+    ("high", &["meter"], AttrType::Number),
+    ("inputmode", &[], AttrType::Enum(&["none", "text", "decimal", "numeric", "tel", "search", "email", "url"])),
+    ("kind", &["track"], AttrType::Enum(&["subtitles", "captions", "descriptions", "chapters", "metadata"])),
+    ("loading", &["iframe", "img"], AttrType::Enum(&["lazy", "eager"])),
+    ("low", &["meter"], AttrType::Number),
+    ("max", &["meter", "progress"], AttrType::Number),
+    ("maxlength", &["input", "textarea"], AttrType::Number),
+    ("method", &["form"], AttrType::Enum(&["get", "post", "dialog"])),
+    ("min", &["meter"], AttrType::Number),
+    ("minlength", &["input", "textarea"], AttrType::Number),
+    ("optimum", &["meter"], AttrType::Number),
+    ("ping", &["a", "area"], AttrType::Text),
+    ("popover", &[], AttrType::Enum(&["", "auto", "manual", "hint"])),
+    ("popovertargetaction", &["button", "input"], AttrType::Enum(&["toggle", "show", "hide"])),
+    ("poster", &["video"], AttrType::Url),
+    ("preload", &["audio", "video"], AttrType::Enum(&["", "none", "metadata", "auto"])),
+    ("referrerpolicy", &[], AttrType::Enum(REFERRER_POLICIES)),
+    ("shadowrootmode", &["template"], AttrType::Enum(&["open", "closed"])),
+    ("spellcheck", &[], AttrType::Enum(&["", "true", "false"])),
+    ("src", &["audio", "embed", "source", "track", "video"], AttrType::Url),
+    ("tabindex", &[], AttrType::Number),
+    ("target", &["form"], AttrType::Target),
+    ("translate", &[], AttrType::Enum(&["", "yes", "no"])),
+    ("type", &["button"], AttrType::Enum(&["submit", "reset", "button"])),
+    (
+        "type",
+        &["input"],
+        AttrType::Enum(&[
+            "button",
+            "checkbox",
+            "color",
+            "date",
+            "datetime-local",
+            "email",
+            "file",
+            "hidden",
+            "image",
+            "month",
+            "number",
+            "password",
+            "radio",
+            "range",
+            "reset",
+            "search",
+            "submit",
+            "tel",
+            "text",
+            "time",
+            "url",
+            "week",
+        ]),
+    ),
+    ("type", &["ol"], AttrType::Text),
+    ("value", &["meter", "progress"], AttrType::Number),
+    (
+        "width",
+        &["canvas", "embed", "iframe", "img", "input", "object", "source", "video"],
+        AttrType::Number,
+    ),
+    ("wrap", &["textarea"], AttrType::Enum(&["soft", "hard"])),
+];
+
+/// What `attr`'s value should be on `tag`, `Text` for attributes without a known type
+pub fn attr_type(tag: &str, attr: &str) -> AttrType {
+    let html5 = HTML5_ATTR_TYPES
+        .iter()
+        .find(|(name, tags, _)| *name == attr && (tags.is_empty() || tags.contains(&tag)));
+
+    // HTML 4 had a few attributes on "all elements but ..." that are global now, e.g. `dir` and `lang`
+    let html4 = || {
+        ATTR_TYPES.iter().find(|(name, tags, _)| {
+            *name == attr && (tags.contains(&tag) || tags.first().is_some_and(|tag| tag.starts_with("all elements")))
+        })
+    };
+
+    html5.or_else(html4).map_or(AttrType::Text, |(_, _, ty)| *ty)
+}
+
+/// Whether `attr` on `tag` is toggled by its presence rather than its value, e.g. `disabled` or `nowrap` on `<td>`.
+/// Enumerated attributes such as `draggable` and `spellcheck` aren't, they take the text `"true"` or `"false"`.
+pub fn is_boolean(tag: &str, attr: &str) -> bool { is_boolean_attr(attr) || attr_type(tag, attr) == AttrType::Boolean }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn html4_types() {
+        assert_eq!(attr_type("td", "colspan"), AttrType::Number);
+        assert_eq!(attr_type("a", "href"), AttrType::Url);
+        assert_eq!(attr_type("input", "checked"), AttrType::Boolean);
+        assert_eq!(attr_type("hr", "align"), AttrType::Enum(&["left", "center", "right"]));
+    }
+
+    #[test]
+    fn html5_types_take_precedence() {
+        let AttrType::Enum(methods) = attr_type("form", "method") else { panic!("`method` should be an enum") };
+        assert!(methods.contains(&"dialog"));
+        let AttrType::Enum(directions) = attr_type("span", "dir") else { panic!("`dir` should be an enum") };
+        assert!(directions.contains(&"auto"));
+        assert_eq!(attr_type("ol", "type"), AttrType::Text);
+        assert_eq!(attr_type("a", "target"), AttrType::Target);
+        assert_eq!(attr_type("img", "width"), AttrType::Number);
+    }
+
+    #[test]
+    fn types_depend_on_the_element() {
+        assert_eq!(attr_type("progress", "value"), AttrType::Number);
+        assert_eq!(attr_type("input", "value"), AttrType::Text);
+        assert_ne!(attr_type("button", "type"), attr_type("input", "type"));
+    }
+
+    #[test]
+    fn boolean_attributes() {
+        assert!(is_boolean("input", "disabled"));
+        assert!(is_boolean("div", "hidden"));
+        assert!(is_boolean("td", "nowrap"));
+        assert!(!is_boolean("div", "draggable"));
+        assert!(!is_boolean("div", "spellcheck"));
+        assert!(!is_boolean("div", "contenteditable"));
+        assert!(!is_boolean("div", "aria-hidden"));
+        assert!(!is_boolean("td", "colspan"));
+    }
+
+    #[test]
+    fn unknown_attributes_are_text() {
+        assert_eq!(attr_type("div", "colspan"), AttrType::Text);
+        assert_eq!(attr_type("div", "data-count"), AttrType::Text);
+        assert_eq!(attr_type("my-element", "href"), AttrType::Text);
+    }
+}
@@ -0,0 +1,10 @@
+use rsx_dominator::events::STATIC_EVENTS;
+
+/// The `rsx_dominator::events` type handling an `on*` prop, e.g. `Click` for `onclick`
+pub fn static_event(prop: &str) -> Option<&'static str> {
+    let event = prop.strip_prefix("on")?;
+    STATIC_EVENTS.binary_search_by_key(&event, |(event, _)| event).ok().map(|i| STATIC_EVENTS[i].1)
+}
+
+/// Every `on*` prop with a type in `rsx_dominator::events`, e.g. `onclick`
+pub fn static_event_props() -> impl Iterator<Item = String> { STATIC_EVENTS.iter().map(|(event, _)| format!("on{event}")) }
@@ -1,143 +1,314 @@
 use tokens::Node;
 
 pub mod attr_props;
+pub mod attr_types;
+pub mod events;
 pub mod tokens;
+pub mod validate;
 
 pub fn type_ident(a: Node) -> Option<String> {
     match a {
+        Node::Fragment(_) => None,
         Node::Text(_) => None,
         Node::Expression(_) => None,
-        Node::Element(element) => Some(
-            match element.ident.to_string().as_str() {
-                "a" => "HTMLAnchorElement",
-                "abbr" => "HTMLElement",
-                "acronym" => "HTMLElement",
-                "address" => "HTMLElement",
-                "area" => "HTMLAreaElement",
-                "article" => "HTMLElement",
-                "aside" => "HTMLElement",
-                "audio" => "HTMLMediaElement",
-                "b" => "HTMLElement",
-                "base" => "HTMLBaseElement",
-                "bdi" => "HTMLElement",
-                "bdo" => "HTMLElement",
-                "big" => "HTMLElement",
-                "blockquote" => "HTMLQuoteElement",
-                "body" => "HTMLBodyElement",
-                "br" => "HTMLBRElement",
-                "button" => "HTMLButtonElement",
-                "canvas" => "HTMLCanvasElement",
-                "caption" => "HTMLTableCaptionElement",
-                "center" => "HTMLElement",
-                "cite" => "HTMLElement",
-                "code" => "HTMLElement",
-                "col" => "HTMLTableColElement",
-                "colgroup" => "HTMLTableColElement",
-                "data" => "HTMLDataElement",
-                "datalist" => "HTMLDataListElement",
-                "dd" => "HTMLElement",
-                "del" => "HTMLModElement",
-                "details" => "HTMLDetailsElement",
-                "dfn" => "HTMLElement",
-                "dialog" => "HTMLDialogElement",
-                "dir" => "HTMLDirectoryElement",
-                "div" => "HTMLDivElement",
-                "dl" => "HTMLDListElement",
-                "dt" => "HTMLElement",
-                "em" => "HTMLElement",
-                "embed" => "HTMLEmbedElement",
-                "fencedframe" => "HTMLFencedFrameElement",
-                "fieldset" => "HTMLFieldSetElement",
-                "figcaption" => "HTMLElement",
-                "figure" => "HTMLElement",
-                "font" => "HTMLFontElement",
-                "footer" => "HTMLElement",
-                "form" => "HTMLFormElement",
-                "frame" => "HTMLElement",
-                "frameset" => "HTMLElement",
-                "head" => "HTMLHeadElement",
-                "header" => "HTMLElement",
-                "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => "HTMLHeadingElement",
-                "hgroup" => "HTMLElement",
-                "hr" => "HTMLHRElement",
-                "html" => "HTMLHtmlElement",
-                "i" => "HTMLElement",
-                "iframe" => "HTMLIFrameElement",
-                "img" => "HTMLImageElement",
-                "input" => "HTMLInputElement",
-                "ins" => "HTMLModElement",
-                "kbd" => "HTMLElement",
-                "label" => "HTMLLabelElement",
-                "legend" => "HTMLLegendElement",
-                "li" => "HTMLLIElement",
-                "link" => "HTMLLinkElement",
-                "main" => "HTMLElement",
-                "map" => "HTMLMapElement",
-                "mark" => "HTMLElement",
-                "marquee" => "HTMLMarqueeElement",
-                "menu" => "HTMLMenuElement",
-                "meta" => "HTMLMetaElement",
-                "meter" => "HTMLMeterElement",
-                "nav" => "HTMLElement",
-                "nobr" => "HTMLElement",
-                "noembed" => "HTMLElement",
-                "noframes" => "HTMLElement",
-                "noscript" => "HTMLElement",
-                "object" => "HTMLObjectElement",
-                "ol" => "HTMLOListElement",
-                "optgroup" => "HTMLOptGroupElement",
-                "option" => "HTMLOptionElement",
-                "output" => "HTMLOutputElement",
-                "p" => "HTMLParagraphElement",
-                "param" => "HTMLParamElement",
-                "picture" => "HTMLPictureElement",
-                "plaintext" => "HTMLElement",
-                "pre" => "HTMLPreElement",
-                "progress" => "HTMLProgressElement",
-                "q" => "HTMLQuoteElement",
-                "rb" => "HTMLElement",
-                "rp" => "HTMLElement",
-                "rt" => "HTMLElement",
-                "rtc" => "HTMLElement",
-                "ruby" => "HTMLElement",
-                "s" => "HTMLElement",
-                "samp" => "HTMLElement",
-                "script" => "HTMLScriptElement",
-                "search" => "HTMLElement",
-                "section" => "HTMLElement",
-                "select" => "HTMLSelectElement",
-                "slot" => "HTMLSlotElement",
-                "small" => "HTMLElement",
-                "source" => "HTMLSourceElement",
-                "span" => "HTMLSpanElement",
-                "strike" => "HTMLElement",
-                "strong" => "HTMLElement",
-                "style" => "HTMLStyleElement",
-                "sub" => "HTMLElement",
-                "summary" => "HTMLElement",
-                "sup" => "HTMLElement",
-                "table" => "HTMLTableElement",
-                "tbody" => "HTMLTableSectionElement",
-                "td" => "HTMLTableCellElement",
-                "template" => "HTMLTemplateElement",
-                "textarea" => "HTMLTextAreaElement",
-                "tfoot" => "HTMLTableSectionElement",
-                "th" => "HTMLTableCellElement",
-                "thead" => "HTMLTableSectionElement",
-                "time" => "HTMLTimeElement",
-                "title" => "HTMLTitleElement",
-                "tr" => "HTMLTableRowElement",
-                "track" => "HTMLTrackElement",
-                "tt" => "HTMLElement",
-                "u" => "HTMLElement",
-                "ul" => "HTMLUListElement",
-                "var" => "HTMLElement",
-                "video" => "HTMLMediaElement",
-                "wbr" => "HTMLElement",
-                "xmp" => "HTMLElement",
-                other => other,
-            }
-            .to_string(),
-        ),
+        Node::If(_) | Node::Match(_) => None,
+        Node::Element(element) => Some(interface_name(&element.name.to_string()).to_string()),
+    }
+}
+
+/// The `web_sys` type for a tag, e.g. `HtmlInputElement` for `input`, or `HtmlElement` for tags without their own
+pub fn web_sys_type(tag: &str) -> String {
+    let Some(name) = interface_name(tag).strip_prefix("HTML").and_then(|name| name.strip_suffix("Element")) else {
+        return "HtmlElement".to_string();
+    };
+
+    // Not part of web-sys's stable API
+    if name == "FencedFrame" || name == "Marquee" {
+        return "HtmlElement".to_string();
+    }
+
+    // All-caps names are only capitalised, e.g. `HTMLBRElement` is `HtmlBrElement`
+    let name = match name.chars().all(|c| c.is_ascii_uppercase()) {
+        true => name.chars().take(1).chain(name.chars().skip(1).map(|c| c.to_ascii_lowercase())).collect(),
+        false => name.to_string(),
+    };
+    format!("Html{name}Element")
+}
+
+/// The `web_sys` type for an SVG tag, e.g. `SvgCircleElement` for `circle`, or `SvgElement` for unknown tags
+pub fn svg_web_sys_type(tag: &str) -> String {
+    let Ok(i) = SVG_ELEMENTS.binary_search_by_key(&tag, |(tag, _)| tag) else {
+        return "SvgElement".to_string();
+    };
+    let name = &SVG_ELEMENTS[i].1["SVG".len()..SVG_ELEMENTS[i].1.len() - "Element".len()];
+
+    // A leading acronym is lowercased, but not the start of the word after it, e.g. `SVGFEBlendElement` is
+    // `SvgfeBlendElement` and `SVGSVGElement` is `SvgsvgElement`
+    let acronym = name.chars().take_while(char::is_ascii_uppercase).count();
+    let lower = match name.len() == acronym {
+        true => acronym,
+        false => acronym.saturating_sub(1),
+    };
+    format!("Svg{}{}Element", name[..lower].to_ascii_lowercase(), &name[lower..])
+}
+
+/// Whether `tag` is an SVG element, names being case-sensitive (e.g. `linearGradient`)
+pub fn is_svg_element(tag: &str) -> bool { SVG_ELEMENTS.binary_search_by_key(&tag, |(tag, _)| tag).is_ok() }
+
+/// The DOM interface for a tag, e.g. `HTMLInputElement` for `input`. Unknown tags are returned as-is.
+pub fn interface_name(tag: &str) -> &str {
+    match ELEMENTS.binary_search_by_key(&tag, |(tag, _)| tag) {
+        Ok(i) => ELEMENTS[i].1,
+        Err(_) => tag,
+    }
+}
+
+/// Whether `tag` is a standard (or legacy) HTML element
+pub fn is_known_element(tag: &str) -> bool { ELEMENTS.binary_search_by_key(&tag, |(tag, _)| tag).is_ok() }
+
+/// HTML elements and their DOM interfaces, sorted by tag
+pub const ELEMENTS: &[(&str, &str)] = &[
+    ("a", "HTMLAnchorElement"),
+    ("abbr", "HTMLElement"),
+    ("acronym", "HTMLElement"),
+    ("address", "HTMLElement"),
+    ("area", "HTMLAreaElement"),
+    ("article", "HTMLElement"),
+    ("aside", "HTMLElement"),
+    ("audio", "HTMLAudioElement"),
+    ("b", "HTMLElement"),
+    ("base", "HTMLBaseElement"),
+    ("bdi", "HTMLElement"),
+    ("bdo", "HTMLElement"),
+    ("big", "HTMLElement"),
+    ("blockquote", "HTMLQuoteElement"),
+    ("body", "HTMLBodyElement"),
+    ("br", "HTMLBRElement"),
+    ("button", "HTMLButtonElement"),
+    ("canvas", "HTMLCanvasElement"),
+    ("caption", "HTMLTableCaptionElement"),
+    ("center", "HTMLElement"),
+    ("cite", "HTMLElement"),
+    ("code", "HTMLElement"),
+    ("col", "HTMLTableColElement"),
+    ("colgroup", "HTMLTableColElement"),
+    ("data", "HTMLDataElement"),
+    ("datalist", "HTMLDataListElement"),
+    ("dd", "HTMLElement"),
+    ("del", "HTMLModElement"),
+    ("details", "HTMLDetailsElement"),
+    ("dfn", "HTMLElement"),
+    ("dialog", "HTMLDialogElement"),
+    ("dir", "HTMLDirectoryElement"),
+    ("div", "HTMLDivElement"),
+    ("dl", "HTMLDListElement"),
+    ("dt", "HTMLElement"),
+    ("em", "HTMLElement"),
+    ("embed", "HTMLEmbedElement"),
+    ("fencedframe", "HTMLFencedFrameElement"),
+    ("fieldset", "HTMLFieldSetElement"),
+    ("figcaption", "HTMLElement"),
+    ("figure", "HTMLElement"),
+    ("font", "HTMLFontElement"),
+    ("footer", "HTMLElement"),
+    ("form", "HTMLFormElement"),
+    ("frame", "HTMLElement"),
+    ("frameset", "HTMLElement"),
+    ("h1", "HTMLHeadingElement"),
+    ("h2", "HTMLHeadingElement"),
+    ("h3", "HTMLHeadingElement"),
+    ("h4", "HTMLHeadingElement"),
+    ("h5", "HTMLHeadingElement"),
+    ("h6", "HTMLHeadingElement"),
+    ("head", "HTMLHeadElement"),
+    ("header", "HTMLElement"),
+    ("hgroup", "HTMLElement"),
+    ("hr", "HTMLHRElement"),
+    ("html", "HTMLHtmlElement"),
+    ("i", "HTMLElement"),
+    ("iframe", "HTMLIFrameElement"),
+    ("img", "HTMLImageElement"),
+    ("input", "HTMLInputElement"),
+    ("ins", "HTMLModElement"),
+    ("kbd", "HTMLElement"),
+    ("label", "HTMLLabelElement"),
+    ("legend", "HTMLLegendElement"),
+    ("li", "HTMLLIElement"),
+    ("link", "HTMLLinkElement"),
+    ("main", "HTMLElement"),
+    ("map", "HTMLMapElement"),
+    ("mark", "HTMLElement"),
+    ("marquee", "HTMLMarqueeElement"),
+    ("menu", "HTMLMenuElement"),
+    ("meta", "HTMLMetaElement"),
+    ("meter", "HTMLMeterElement"),
+    ("nav", "HTMLElement"),
+    ("nobr", "HTMLElement"),
+    ("noembed", "HTMLElement"),
+    ("noframes", "HTMLElement"),
+    ("noscript", "HTMLElement"),
+    ("object", "HTMLObjectElement"),
+    ("ol", "HTMLOListElement"),
+    ("optgroup", "HTMLOptGroupElement"),
+    ("option", "HTMLOptionElement"),
+    ("output", "HTMLOutputElement"),
+    ("p", "HTMLParagraphElement"),
+    ("param", "HTMLParamElement"),
+    ("picture", "HTMLPictureElement"),
+    ("plaintext", "HTMLElement"),
+    ("pre", "HTMLPreElement"),
+    ("progress", "HTMLProgressElement"),
+    ("q", "HTMLQuoteElement"),
+    ("rb", "HTMLElement"),
+    ("rp", "HTMLElement"),
+    ("rt", "HTMLElement"),
+    ("rtc", "HTMLElement"),
+    ("ruby", "HTMLElement"),
+    ("s", "HTMLElement"),
+    ("samp", "HTMLElement"),
+    ("script", "HTMLScriptElement"),
+    ("search", "HTMLElement"),
+    ("section", "HTMLElement"),
+    ("select", "HTMLSelectElement"),
+    ("slot", "HTMLSlotElement"),
+    ("small", "HTMLElement"),
+    ("source", "HTMLSourceElement"),
+    ("span", "HTMLSpanElement"),
+    ("strike", "HTMLElement"),
+    ("strong", "HTMLElement"),
+    ("style", "HTMLStyleElement"),
+    ("sub", "HTMLElement"),
+    ("summary", "HTMLElement"),
+    ("sup", "HTMLElement"),
+    ("table", "HTMLTableElement"),
+    ("tbody", "HTMLTableSectionElement"),
+    ("td", "HTMLTableCellElement"),
+    ("template", "HTMLTemplateElement"),
+    ("textarea", "HTMLTextAreaElement"),
+    ("tfoot", "HTMLTableSectionElement"),
+    ("th", "HTMLTableCellElement"),
+    ("thead", "HTMLTableSectionElement"),
+    ("time", "HTMLTimeElement"),
+    ("title", "HTMLTitleElement"),
+    ("tr", "HTMLTableRowElement"),
+    ("track", "HTMLTrackElement"),
+    ("tt", "HTMLElement"),
+    ("u", "HTMLElement"),
+    ("ul", "HTMLUListElement"),
+    ("var", "HTMLElement"),
+    ("video", "HTMLVideoElement"),
+    ("wbr", "HTMLElement"),
+    ("xmp", "HTMLElement"),
+];
+
+/// SVG elements and their DOM interfaces, sorted by tag
+/// https://developer.mozilla.org/en-US/docs/Web/SVG/Element
+pub const SVG_ELEMENTS: &[(&str, &str)] = &[
+    ("a", "SVGAElement"),
+    ("animate", "SVGAnimateElement"),
+    ("animateMotion", "SVGAnimateMotionElement"),
+    ("animateTransform", "SVGAnimateTransformElement"),
+    ("circle", "SVGCircleElement"),
+    ("clipPath", "SVGClipPathElement"),
+    ("defs", "SVGDefsElement"),
+    ("desc", "SVGDescElement"),
+    ("ellipse", "SVGEllipseElement"),
+    ("feBlend", "SVGFEBlendElement"),
+    ("feColorMatrix", "SVGFEColorMatrixElement"),
+    ("feComponentTransfer", "SVGFEComponentTransferElement"),
+    ("feComposite", "SVGFECompositeElement"),
+    ("feConvolveMatrix", "SVGFEConvolveMatrixElement"),
+    ("feDiffuseLighting", "SVGFEDiffuseLightingElement"),
+    ("feDisplacementMap", "SVGFEDisplacementMapElement"),
+    ("feDistantLight", "SVGFEDistantLightElement"),
+    ("feDropShadow", "SVGFEDropShadowElement"),
+    ("feFlood", "SVGFEFloodElement"),
+    ("feFuncA", "SVGFEFuncAElement"),
+    ("feFuncB", "SVGFEFuncBElement"),
+    ("feFuncG", "SVGFEFuncGElement"),
+    ("feFuncR", "SVGFEFuncRElement"),
+    ("feGaussianBlur", "SVGFEGaussianBlurElement"),
+    ("feImage", "SVGFEImageElement"),
+    ("feMerge", "SVGFEMergeElement"),
+    ("feMergeNode", "SVGFEMergeNodeElement"),
+    ("feMorphology", "SVGFEMorphologyElement"),
+    ("feOffset", "SVGFEOffsetElement"),
+    ("fePointLight", "SVGFEPointLightElement"),
+    ("feSpecularLighting", "SVGFESpecularLightingElement"),
+    ("feSpotLight", "SVGFESpotLightElement"),
+    ("feTile", "SVGFETileElement"),
+    ("feTurbulence", "SVGFETurbulenceElement"),
+    ("filter", "SVGFilterElement"),
+    ("foreignObject", "SVGForeignObjectElement"),
+    ("g", "SVGGElement"),
+    ("image", "SVGImageElement"),
+    ("line", "SVGLineElement"),
+    ("linearGradient", "SVGLinearGradientElement"),
+    ("marker", "SVGMarkerElement"),
+    ("mask", "SVGMaskElement"),
+    ("metadata", "SVGMetadataElement"),
+    ("mpath", "SVGMPathElement"),
+    ("path", "SVGPathElement"),
+    ("pattern", "SVGPatternElement"),
+    ("polygon", "SVGPolygonElement"),
+    ("polyline", "SVGPolylineElement"),
+    ("radialGradient", "SVGRadialGradientElement"),
+    ("rect", "SVGRectElement"),
+    ("script", "SVGScriptElement"),
+    ("set", "SVGSetElement"),
+    ("stop", "SVGStopElement"),
+    ("style", "SVGStyleElement"),
+    ("svg", "SVGSVGElement"),
+    ("switch", "SVGSwitchElement"),
+    ("symbol", "SVGSymbolElement"),
+    ("text", "SVGTextElement"),
+    ("textPath", "SVGTextPathElement"),
+    ("title", "SVGTitleElement"),
+    ("tspan", "SVGTSpanElement"),
+    ("use", "SVGUseElement"),
+    ("view", "SVGViewElement"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn web_sys_types() {
+        assert_eq!(web_sys_type("input"), "HtmlInputElement");
+        assert_eq!(web_sys_type("textarea"), "HtmlTextAreaElement");
+        assert_eq!(web_sys_type("iframe"), "HtmlIFrameElement");
+        assert_eq!(web_sys_type("br"), "HtmlBrElement");
+        assert_eq!(web_sys_type("li"), "HtmlLiElement");
+        assert_eq!(web_sys_type("section"), "HtmlElement");
+    }
+
+    #[test]
+    fn unknown_web_sys_types_are_html_elements() {
+        assert_eq!(web_sys_type("my-element"), "HtmlElement");
+        assert_eq!(web_sys_type("Card"), "HtmlElement");
+        assert_eq!(web_sys_type("marquee"), "HtmlElement");
+    }
+
+    #[test]
+    fn svg_web_sys_types() {
+        assert_eq!(svg_web_sys_type("circle"), "SvgCircleElement");
+        assert_eq!(svg_web_sys_type("linearGradient"), "SvgLinearGradientElement");
+        assert_eq!(svg_web_sys_type("foreignObject"), "SvgForeignObjectElement");
+        // Leading acronyms
+        assert_eq!(svg_web_sys_type("svg"), "SvgsvgElement");
+        assert_eq!(svg_web_sys_type("a"), "SvgaElement");
+        assert_eq!(svg_web_sys_type("g"), "SvggElement");
+        assert_eq!(svg_web_sys_type("feBlend"), "SvgfeBlendElement");
+        assert_eq!(svg_web_sys_type("tspan"), "SvgtSpanElement");
+        assert_eq!(svg_web_sys_type("mpath"), "SvgmPathElement");
+    }
+
+    #[test]
+    fn unknown_svg_web_sys_types_are_svg_elements() {
+        assert_eq!(svg_web_sys_type("cirle"), "SvgElement");
+        assert_eq!(svg_web_sys_type("div"), "SvgElement");
+        // Tags are case-sensitive
+        assert_eq!(svg_web_sys_type("lineargradient"), "SvgElement");
     }
 }
@@ -1,9 +1,10 @@
 use {
-    proc_macro2::Literal,
-    std::fmt::Debug,
+    proc_macro2::{Literal, Span},
+    std::fmt::{Debug, Display},
     syn::{
         Expr, Ident, Token,
-        parse::{Parse, ParseStream, discouraged::Speculative},
+        ext::IdentExt,
+        parse::{Parse, ParseStream},
     },
 };
 
@@ -20,165 +21,552 @@ impl Parse for ShortClose {
 
 /// </
 #[derive(Copy, Debug, Clone)]
-pub struct ShortOpen;
+pub struct ShortOpen(pub Span);
 impl Parse for ShortOpen {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        input.parse::<Token![<]>()?;
+        let open = input.parse::<Token![<]>()?;
         input.parse::<Token![/]>()?;
-        Ok(Self)
+        Ok(Self(open.span))
+    }
+}
+
+/// A tag or prop name, idents joined by `-` or `:`
+/// e.g. `div`, `sl-button`, `aria-label`, `xlink:href`
+#[derive(Clone)]
+pub struct Name {
+    pub idents: Vec<Ident>,
+    separators: Vec<char>,
+    value: String,
+}
+
+impl Name {
+    /// The plain ident, if the name has no `-` or `:` parts
+    pub fn as_ident(&self) -> Option<&Ident> {
+        match self.idents.as_slice() {
+            [ident] => Some(ident),
+            _ => None,
+        }
     }
+
+    pub fn span(&self) -> Span { self.idents[0].span() }
+
+    /// Splits `prefix:name` into its prefix and local name
+    pub fn namespace(&self) -> Option<(&str, &str)> { self.value.split_once(':') }
+}
+
+impl Parse for Name {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // Keywords are valid names (type, for, async, ...)
+        let first = Ident::parse_any(input)?;
+        let mut value = first.to_string();
+        let mut idents = vec![first];
+        let mut separators = Vec::new();
+
+        loop {
+            let separator = if input.peek(Token![-]) && input.peek2(Ident::peek_any) {
+                input.parse::<Token![-]>()?;
+                '-'
+            } else if input.peek(Token![:]) && !input.peek(Token![::]) && input.peek2(Ident::peek_any) {
+                input.parse::<Token![:]>()?;
+                ':'
+            } else {
+                break;
+            };
+
+            let ident = Ident::parse_any(input)?;
+            value.push(separator);
+            value.push_str(&ident.to_string());
+            separators.push(separator);
+            idents.push(ident);
+        }
+
+        Ok(Name { idents, separators, value })
+    }
+}
+
+// Lets errors span the whole name rather than its first ident
+impl quote::ToTokens for Name {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.idents[0].to_tokens(tokens);
+        for (separator, ident) in self.separators.iter().zip(&self.idents[1..]) {
+            let mut punct = proc_macro2::Punct::new(*separator, proc_macro2::Spacing::Alone);
+            punct.set_span(ident.span());
+            punct.to_tokens(tokens);
+            ident.to_tokens(tokens);
+        }
+    }
+}
+
+impl Display for Name {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { f.write_str(&self.value) }
+}
+
+impl Debug for Name {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { f.debug_tuple("Name").field(&self.value).finish() }
+}
+
+impl PartialEq for Name {
+    fn eq(&self, other: &Self) -> bool { self.value == other.value }
+}
+
+impl PartialEq<str> for Name {
+    fn eq(&self, other: &str) -> bool { self.value == other }
+}
+
+impl PartialEq<&str> for Name {
+    fn eq(&self, other: &&str) -> bool { self.value == *other }
 }
 
 #[derive(Debug, Clone)]
 pub struct Element {
     pub props: Vec<Prop>,
-    pub ident: Ident,
+    /// `{..expr}`, applied before `props` so explicit props win
+    pub spreads: Vec<Spread>,
+    pub name: Name,
+    pub children: Vec<Box<Node>>,
+    /// Whether it's created in the SVG namespace, see [`mark_svg`]
+    pub svg: bool,
+}
+
+/// {..expr}
+#[derive(Clone)]
+pub struct Spread(pub Expr);
+
+impl Debug for Spread {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { f.debug_tuple("Spread").field(&"..").finish() }
+}
+
+impl Parse for Spread {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let content;
+        syn::braced!(content in input);
+        content.parse::<Token![..]>()?;
+        Ok(Spread(content.parse()?))
+    }
+}
+
+/// <>...</>
+#[derive(Debug, Clone)]
+pub struct Fragment {
     pub children: Vec<Box<Node>>,
 }
 
 #[derive(Clone)]
 pub enum Node {
     Element(Element),
+    Fragment(Fragment),
     Text(String),
     Expression(Expr),
+    If(If),
+    Match(Match),
+}
+
+/// {if cond { nodes } else if cond { nodes } else { nodes }}
+#[derive(Clone)]
+pub struct If {
+    pub branches: Vec<(Expr, Vec<Node>)>,
+    pub otherwise: Option<Vec<Node>>,
+}
+
+/// {match expr { pat => <node/>, pat if guard => { nodes } }}
+#[derive(Clone)]
+pub struct Match {
+    pub expr: Expr,
+    pub arms: Vec<Arm>,
+}
+
+#[derive(Clone)]
+pub struct Arm {
+    pub pat: syn::Pat,
+    pub guard: Option<Expr>,
+    pub body: Vec<Node>,
+}
+
+impl Parse for If {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut branches = Vec::new();
+        let mut otherwise = None;
+
+        loop {
+            input.parse::<Token![if]>()?;
+            if input.peek(Token![let]) {
+                return Err(input.error("`if let` isn't supported in markup, use `{match ..}` instead"));
+            }
+
+            let cond = Expr::parse_without_eager_brace(input)?;
+            branches.push((cond, parse_block(input)?));
+
+            if input.parse::<Option<Token![else]>>()?.is_none() {
+                break;
+            }
+
+            if !input.peek(Token![if]) {
+                otherwise = Some(parse_block(input)?);
+                break;
+            }
+        }
+
+        Ok(If { branches, otherwise })
+    }
+}
+
+impl Parse for Match {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![match]>()?;
+        let expr = Expr::parse_without_eager_brace(input)?;
+
+        let content;
+        syn::braced!(content in input);
+
+        let mut arms = Vec::new();
+        while !content.is_empty() {
+            let pat = syn::Pat::parse_multi_with_leading_vert(&content)?;
+            let guard = match content.parse::<Option<Token![if]>>()? {
+                Some(_) => Some(content.parse()?),
+                None => None,
+            };
+            content.parse::<Token![=>]>()?;
+
+            let body = if content.peek(syn::token::Brace) {
+                parse_block(&content)?
+            } else if content.peek(Token![<]) {
+                vec![content.parse::<Node>()?]
+            } else {
+                return Err(content.error("Expected markup or `{ nodes }` for this match arm"));
+            };
+            content.parse::<Option<Token![,]>>()?;
+
+            arms.push(Arm { pat, guard, body });
+        }
+
+        Ok(Match { expr, arms })
+    }
+}
+
+/// { nodes }, the body of an if / else / match arm
+fn parse_block(input: ParseStream) -> syn::Result<Vec<Node>> {
+    let content;
+    syn::braced!(content in input);
+
+    let mut nodes = Vec::new();
+    while !content.is_empty() {
+        nodes.push(content.parse::<Node>()?);
+    }
+
+    Ok(space_expressions(nodes))
 }
 
 impl std::fmt::Debug for Node {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Node::Element(e) => f.debug_tuple("Element").field(e).finish(),
+            Node::Fragment(fr) => f.debug_tuple("Fragment").field(fr).finish(),
             Node::Text(t) => f.debug_tuple("Text").field(t).finish(),
             Node::Expression(_) => f.debug_tuple("Expression").field(&"<expr>").finish(),
+            Node::If(i) => f.debug_tuple("If").field(&i.branches.iter().map(|(_, nodes)| nodes).collect::<Vec<_>>()).finish(),
+            Node::Match(m) => f.debug_tuple("Match").field(&m.arms.iter().map(|arm| &arm.body).collect::<Vec<_>>()).finish(),
         }
     }
 }
 
-macro_rules! er {
-    ($input: expr, $pat: literal, $( $rest: expr ),* ) => {
-        syn::Error::new_spanned(
-            $input.cursor().token_stream(),
-            format!($pat, $($rest,)*),
-        )
-    };
-}
+impl Parse for Element {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        // {<} {element} {name=value | {..spread}}? {>} {children}? {</} {element} {>}
+        // {<} {element} {name=value | {..spread}}? {/>}
 
-macro_rules! try_rw {
-    ($input: expr, $t: ty ) => {{
-        let ff_input = $input.fork();
-        match ff_input.parse::<$t>() {
-            Err(_) => None,
-            Ok(v) => {
-                // println!("{} -> {}", $input.to_string(), ff_input.to_string());
-                $input.advance_to(&ff_input);
-                Some(v)
+        input.parse::<Token![<]>()?;
+
+        if !input.peek(Ident::peek_any) {
+            return Err(input.error("Expected an element name after `<`"));
+        }
+        let name = input.parse::<Name>()?;
+
+        let mut props = Vec::new();
+        let mut spreads = Vec::new();
+        while !(input.peek(Token![>]) || input.peek(Token![/]) && input.peek2(Token![>])) {
+            if input.is_empty() {
+                return Err(syn::Error::new_spanned(&name, format!("Unclosed tag, expected `>` or `/>` after `<{name}`")));
+            } else if input.peek(syn::token::Brace) {
+                spreads.push(input.parse::<Spread>()?);
+            } else if input.peek(Ident::peek_any) {
+                props.push(input.parse::<Prop>()?);
+            } else {
+                return Err(input.error(format!("Expected a prop name, `>` or `/>` in `<{name}>`")));
             }
         }
-    }};
+
+        // Early close via {/>} skips parsing children
+        if input.peek(Token![/]) {
+            input.parse::<ShortClose>()?;
+            return Ok(Element { props, spreads, name, children: Vec::new(), svg: false });
+        }
+        input.parse::<Token![>]>()?;
+
+        let children = if is_raw_text_element(&name, &props) {
+            vec![Node::Text(parse_text(input, true)?)]
+        } else {
+            parse_children(input, &name, &format!("<{name}>"))?
+        };
+
+        let close = input.parse::<ShortOpen>()?;
+        if !input.peek(Ident::peek_any) {
+            return Err(syn::Error::new(close.0, format!("Expected `</{name}>`, found `</`")).with_opening(&name, &name));
+        }
+
+        let name_2 = input.parse::<Name>()?;
+        if name != name_2 {
+            return Err(syn::Error::new_spanned(&name_2, format!("Expected `</{name}>`, found `</{name_2}>`"))
+                .with_opening(&name, &name));
+        }
+
+        input
+            .parse::<Token![>]>()
+            .map_err(|_| syn::Error::new_spanned(&name_2, format!("Expected `>` to finish `</{name_2}`")))?;
+
+        Ok(Element { props, spreads, name, children: children.into_iter().map(Box::new).collect(), svg: false })
+    }
 }
 
-impl Parse for Element {
+impl Parse for Fragment {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        // {<} {element} {name=value}? {>} {children}? {</} {element} {>}
-        // {<} {element} {name=value}? {/>}
+        // {<} {>} {children}? {</} {>}
 
-        input.parse::<Token![<]>().map_err(|e| er!(input, "Missing opening tri-brace: {:?}", e))?;
+        let open = input.parse::<Token![<]>()?;
+        input.parse::<Token![>]>()?;
 
-        let ident = input.parse::<Ident>().map_err(|e| er!(input, "Missing element name: {:?}", e))?;
+        let children = parse_children(input, &open, "<>")?;
 
-        let mut props = Vec::new();
-        while let Some(prop) = try_rw!(input, Prop) {
-            props.push(prop);
+        let close = input.parse::<ShortOpen>()?;
+        input.parse::<Token![>]>().map_err(|_| match input.cursor().token_tree() {
+            Some((tt, _)) => syn::Error::new(close.0, format!("Expected `</>`, found `</{tt}>`")).with_opening(&open, ""),
+            None => syn::Error::new(close.0, "Expected `</>`"),
+        })?;
+
+        Ok(Fragment { children: children.into_iter().map(Box::new).collect() })
+    }
+}
+
+trait WithOpening {
+    /// Adds a note pointing at the opening tag a closing error belongs to
+    fn with_opening(self, span: &impl quote::ToTokens, tag: impl Display) -> Self;
+}
+
+impl WithOpening for syn::Error {
+    fn with_opening(mut self, span: &impl quote::ToTokens, tag: impl Display) -> Self {
+        self.combine(syn::Error::new_spanned(span, format!("`<{tag}>` opened here")));
+        self
+    }
+}
+
+/// `<style>` and `<script>` (without src) hold CSS / JS, not markup
+pub fn is_raw_text_element(name: &Name, props: &[Prop]) -> bool {
+    *name == "style" || *name == "script" && !props.iter().any(|prop| prop.name == "src")
+}
+
+/// Parses nodes up to the closing {</}, erroring at `open` if the input runs out first
+fn parse_children(input: ParseStream, open: &impl quote::ToTokens, tag: &str) -> syn::Result<Vec<Node>> {
+    let mut children = Vec::new();
+
+    loop {
+        if input.is_empty() {
+            return Err(syn::Error::new_spanned(open, format!("Unclosed tag `{tag}`")));
         }
 
-        // Early close via {/>} skips parsing children
-        let mut children = Vec::new();
-        if input.parse::<ShortClose>().is_err() {
-            input.parse::<Token![>]>().map_err(|e| er!(input, "Missing closing tri-brace: {:?}", e))?;
+        if input.peek(Token![<]) && input.peek2(Token![/]) {
+            break;
+        }
+
+        children.push(input.parse::<Node>()?);
+    }
+
+    Ok(space_expressions(children))
+}
+
+/// Whitespace isn't kept in token streams, so words either side of an `{expr}` are spaced like they are between
+/// words in a text run
+fn space_expressions(nodes: Vec<Node>) -> Vec<Node> {
+    let starts_with_word = |t: &str| t.starts_with(|c: char| c.is_alphanumeric() || c == '"' || c == '\'');
+    let ends_with_word = |t: &str| t.ends_with(|c: char| c.is_alphanumeric() || c == '"' || c == '\'');
+
+    let mut spaced: Vec<Node> = Vec::with_capacity(nodes.len());
+    for node in nodes {
+        match (spaced.last_mut(), &node) {
+            (Some(Node::Text(text)), Node::Expression(_)) if ends_with_word(text) => text.push(' '),
+            (Some(Node::Expression(_)), Node::Expression(_)) => spaced.push(Node::Text(" ".to_string())),
+            (Some(Node::Expression(_)), Node::Text(text)) if starts_with_word(text) => {
+                spaced.push(Node::Text(format!(" {text}")));
+                continue;
+            }
+            _ => {}
+        }
+
+        spaced.push(node);
+    }
 
-            while let Some(el) = try_rw!(input, Node) {
-                children.push(el);
+    spaced
+}
+
+/// Collects a run of text tokens. Markup stops at `<` and `{`, raw text (CSS / JS) only stops at `</`
+fn parse_text(input: ParseStream, raw: bool) -> syn::Result<String> {
+    input.step(|sc| {
+        let mut text = String::new();
+        let mut cursor = *sc;
+        let mut prev_was_ident_or_literal = false;
+
+        loop {
+            if let Some((p, next)) = cursor.punct() {
+                if p.as_char() == '<' && (!raw || next.punct().is_some_and(|(p, _)| p.as_char() == '/')) {
+                    break;
+                }
+
+                if p.as_char() == '>' && !raw {
+                    return Err(syn::Error::new(p.span(), "Unexpected `>` in text, use `{\">\"}` to write it out"));
+                }
+
+                // For punct, don't add space before it
+                // But reset the flag for next token
+                text.push(p.as_char());
+                prev_was_ident_or_literal = false;
+                cursor = next;
+                continue;
             }
-            input.parse::<ShortOpen>()?;
-            let ident_2 = input.parse::<Ident>().map_err(|e| er!(input, "Missing element name: {:?}", e))?;
 
-            if ident != ident_2 {
-                dbg!("mismatching ident");
-                panic!() // TODO
+            if !raw && cursor.group(proc_macro2::Delimiter::Brace).is_some() {
+                break;
             }
 
-            input.parse::<Token![>]>()?;
+            if let Some((tt, c)) = cursor.token_tree() {
+                // Add space before this token if the previous was an ident/literal
+                if prev_was_ident_or_literal {
+                    text.push(' ');
+                }
+
+                text.push_str(&tt.to_string());
+                prev_was_ident_or_literal = true;
+                cursor = c;
+            } else {
+                break;
+            }
+        }
+
+        Ok((text, cursor))
+    })
+}
+
+/// Top level of an `rsx!` invocation, one or more sibling nodes
+#[derive(Debug, Clone)]
+pub struct Nodes(pub Vec<Node>);
+
+impl Parse for Nodes {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut nodes = Vec::new();
+        while !input.is_empty() {
+            nodes.push(input.parse::<Node>()?);
         }
 
-        Ok(Element { props, ident, children: children.into_iter().map(Box::new).collect() })
+        if nodes.is_empty() {
+            return Err(input.error("Expected markup, e.g. rsx!(<tag>content</tag>) or rsx!(<>content</>)"));
+        }
+
+        let mut nodes = space_expressions(nodes);
+        nodes.iter_mut().for_each(|node| mark_svg(node, false));
+        Ok(Nodes(nodes))
+    }
+}
+
+/// Marks the elements that belong in the SVG namespace: `<svg>` and everything inside it, up to the children of a
+/// `<foreignObject>` which are HTML again. Tags that only exist in SVG, such as `<circle>`, are SVG anywhere, so a
+/// `rsx!(<circle />)` can be rendered into an `<svg>` elsewhere.
+fn mark_svg(node: &mut Node, svg: bool) {
+    match node {
+        Node::Element(element) => {
+            let tag = element.name.to_string();
+            // Components pass the namespace through to their children
+            let children = match tag.starts_with(char::is_uppercase) {
+                true => svg,
+                false => {
+                    element.svg = svg || crate::is_svg_element(&tag) && !crate::is_known_element(&tag);
+                    element.svg && tag != "foreignObject"
+                }
+            };
+            element.children.iter_mut().for_each(|child| mark_svg(child, children));
+        }
+        Node::Fragment(fragment) => fragment.children.iter_mut().for_each(|child| mark_svg(child, svg)),
+        Node::If(if_node) => if_node
+            .branches
+            .iter_mut()
+            .map(|(_, nodes)| nodes)
+            .chain(&mut if_node.otherwise)
+            .flatten()
+            .for_each(|child| mark_svg(child, svg)),
+        Node::Match(match_node) => {
+            match_node.arms.iter_mut().flat_map(|arm| &mut arm.body).for_each(|child| mark_svg(child, svg))
+        }
+        Node::Text(_) | Node::Expression(_) => {}
     }
 }
 
 impl Parse for Node {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         if input.is_empty() {
-            return Err(er!(input, "Nothing to parse... {}", ":("));
+            return Err(input.error("Expected an element, text or `{expression}`"));
         }
 
         // Check for curly brace expressions first
         if input.peek(syn::token::Brace) {
             let content;
             syn::braced!(content in input);
+
+            // Conditionals hold markup, so can't be parsed as plain expressions
+            let node = if content.peek(Token![if]) {
+                Some(Node::If(content.parse()?))
+            } else if content.peek(Token![match]) {
+                Some(Node::Match(content.parse()?))
+            } else {
+                None
+            };
+            if let Some(node) = node {
+                if !content.is_empty() {
+                    return Err(content.error("Unexpected tokens after conditional"));
+                }
+                return Ok(node);
+            }
+
             let expr: Expr = content.parse()?;
+            if !content.is_empty() {
+                return Err(content.error("Expected a single expression in `{..}`"));
+            }
             return Ok(Node::Expression(expr));
         }
 
-        Ok(match try_rw!(input, Element) {
-            Some(v) => Node::Element(v),
-            None => {
-                let text = input.step(|sc| {
-                    let mut text = String::new();
-                    let mut cursor = *sc;
-                    let mut prev_was_ident_or_literal = false;
-
-                    loop {
-                        if let Some((p, _)) = cursor.punct() {
-                            if p.as_char() == '<' || p.as_char() == '{' {
-                                break;
-                            }
-                            // For punct, don't add space before it
-                            // But reset the flag for next token
-                            text.push(p.as_char());
-                            prev_was_ident_or_literal = false;
-                            cursor = cursor.punct().unwrap().1;
-                            continue;
-                        }
-
-                        if let Some((tt, c)) = cursor.token_tree() {
-                            // Add space before this token if the previous was an ident/literal
-                            if prev_was_ident_or_literal {
-                                text.push(' ');
-                            }
-
-                            text.push_str(&tt.to_string());
-                            prev_was_ident_or_literal = true;
-                            cursor = c;
-                        } else {
-                            break;
-                        }
-                    }
-
-                    Ok((text, cursor))
-                })?;
-
-                if text.is_empty() {
-                    return Err(er!(input, "Empty text {}", ":("));
-                }
+        if input.peek(Token![<]) {
+            // Fragments open with {<} {>}
+            if input.peek2(Token![>]) {
+                return Ok(Node::Fragment(input.parse()?));
+            }
 
-                Node::Text(text)
+            // A closing tag with nothing open for it
+            if input.peek2(Token![/]) {
+                let close = input.parse::<ShortOpen>()?;
+                return Err(match input.parse::<Name>() {
+                    Ok(name) => syn::Error::new_spanned(&name, format!("Unexpected closing tag `</{name}>`")),
+                    Err(_) => syn::Error::new(close.0, "Unexpected closing tag"),
+                });
             }
-        })
+
+            return Ok(Node::Element(input.parse()?));
+        }
+
+        Ok(Node::Text(parse_text(input, false)?))
     }
 }
 
 #[derive(Clone)]
 pub struct Prop {
-    pub name: Ident,
+    pub name: Name,
     pub value: Expr,
+    /// The properties of a `style={{ .. }}` object, whose `value` is then the object's tokens as written
+    pub style: Option<Vec<StyleProperty>>,
 }
 
 impl Debug for Prop {
@@ -189,39 +577,146 @@ impl Debug for Prop {
 
 impl Parse for Prop {
     // {name}={value}
-    // TODO: {name} (implies value=true)
+    // {name} (implies value=true)
 
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let name = input.parse::<Ident>().map_err(|e| er!(input, "Missing prop name: {:?}", e))?;
-        input.parse::<Token![=]>().map_err(|e| er!(input, "Missing token equals '=': {:?}", e))?;
+        let name = input.parse::<Name>()?;
+
+        // Bare boolean attribute, e.g. <input disabled />
+        if input.parse::<Option<Token![=]>>()?.is_none() {
+            let value = Expr::Lit(syn::ExprLit {
+                attrs: Vec::new(),
+                lit: syn::Lit::Bool(syn::LitBool { value: true, span: name.span() }),
+            });
+
+            return Ok(Prop { name, value, style: None });
+        }
+
+        if name == "style" && is_style_object(input) {
+            let content;
+            syn::braced!(content in input);
+            let value = Expr::Verbatim(content.fork().parse()?);
+            let style = content.call(parse_style_object)?;
+            return Ok(Prop { name, value, style: Some(style) });
+        }
 
         let value = if input.peek(syn::token::Brace) {
             // Handle braced expressions like {|_| ...}
             let content;
             syn::braced!(content in input);
-            content.parse::<Expr>()?
-        } else if input.peek(syn::LitStr) {
-            // Handle string literals directly to avoid parsing issues with special characters
-            let lit: syn::LitStr = input.parse()?;
-            Expr::Lit(syn::ExprLit { attrs: Vec::new(), lit: syn::Lit::Str(lit) })
+            let expr = content.parse::<Expr>()?;
+            if !content.is_empty() {
+                return Err(content.error(format!("Expected a single expression for `{name}`")));
+            }
+            expr
+        } else if input.peek(syn::Lit) {
+            // Handle literals directly to avoid parsing issues with special characters
+            Expr::Lit(syn::ExprLit { attrs: Vec::new(), lit: input.parse()? })
+        } else if input.peek(Token![-]) && input.peek2(syn::Lit) {
+            // Negative numbers
+            let neg = input.parse::<Token![-]>()?;
+            let lit = input.parse::<Literal>()?;
+            let expr = Expr::Lit(syn::ExprLit { attrs: Vec::new(), lit: syn::Lit::Verbatim(lit) });
+            Expr::Unary(syn::ExprUnary { attrs: Vec::new(), op: syn::UnOp::Neg(neg), expr: Box::new(expr) })
+        } else if input.peek(Ident) || input.peek(Token![::]) {
+            // Plain paths, e.g. value=SOME_CONST
+            Expr::Path(syn::ExprPath { attrs: Vec::new(), qself: None, path: input.call(syn::Path::parse_mod_style)? })
         } else {
-            try_rw!(input, Expr)
-                .or_else(|| {
-                    try_rw!(input, Literal)
-                        .map(|v| Expr::Lit(syn::ExprLit { attrs: Vec::new(), lit: syn::Lit::Verbatim(v) }))
-                })
-                .ok_or(er!(input, "Missing prop value: {:?}", ":("))?
+            return Err(input.error(format!("Expected a value for `{name}`, either a literal or `{{expression}}`")));
         };
 
-        Ok(Prop { name, value })
+        Ok(Prop { name, value, style: None })
+    }
+}
+
+/// `name: value` in a `style={{ .. }}` object, e.g. `color: signal` or `"margin-top": "4px" !important`
+#[derive(Clone)]
+pub struct StyleProperty {
+    /// The CSS property, either written as a string or as idents joined by `-`
+    pub name: syn::LitStr,
+    pub value: Expr,
+    pub important: bool,
+}
+
+// `style={{` with a `name:` or nothing inside, rather than a block expression such as `style={{ let x = ..; x }}`
+fn is_style_object(input: ParseStream) -> bool {
+    let fork = input.fork();
+    let object = (|| {
+        let outer;
+        syn::braced!(outer in fork);
+        let inner;
+        syn::braced!(inner in outer);
+        Ok::<_, syn::Error>((outer.is_empty(), inner))
+    })();
+
+    match object {
+        Ok((true, content)) => {
+            content.is_empty()
+                || (parse_style_name(&content).is_ok() && content.peek(Token![:]) && !content.peek(Token![::]))
+        }
+        _ => false,
+    }
+}
+
+fn parse_style_name(input: ParseStream) -> syn::Result<syn::LitStr> {
+    if input.peek(syn::LitStr) {
+        return input.parse();
+    }
+
+    let first = Ident::parse_any(input)?;
+    let mut name = first.to_string();
+    while input.peek(Token![-]) && input.peek2(Ident::peek_any) {
+        input.parse::<Token![-]>()?;
+        name.push('-');
+        name.push_str(&Ident::parse_any(input)?.to_string());
     }
+    Ok(syn::LitStr::new(&name, first.span()))
+}
+
+fn parse_style_object(input: ParseStream) -> syn::Result<Vec<StyleProperty>> {
+    let content;
+    syn::braced!(content in input);
+
+    let mut properties = Vec::new();
+    while !content.is_empty() {
+        let name = content.call(parse_style_name)?;
+        content.parse::<Token![:]>()?;
+
+        // Everything up to the next comma, so `signal !important` isn't read as a `signal!` macro call
+        let mut tokens = Vec::new();
+        while !content.is_empty() && !content.peek(Token![,]) {
+            tokens.push(content.parse::<proc_macro2::TokenTree>()?);
+        }
+
+        let important = matches!(
+            tokens.as_slice(),
+            [.., proc_macro2::TokenTree::Punct(bang), proc_macro2::TokenTree::Ident(important)]
+                if bang.as_char() == '!' && important == "important"
+        );
+        if important {
+            tokens.truncate(tokens.len() - 2);
+        }
+
+        if tokens.is_empty() {
+            return Err(syn::Error::new(name.span(), format!("Expected a value for `{}`", name.value())));
+        }
+        let value = syn::parse2::<Expr>(tokens.into_iter().collect())?;
+
+        properties.push(StyleProperty { name, value, important });
+
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+    }
+
+    Ok(properties)
 }
 
 impl From<Element> for proc_macro2::TokenStream {
     fn from(val: Element) -> Self {
         use quote::quote;
 
-        let tag_name = &val.ident;
+        let tag_name = &val.name;
         let tag_str = tag_name.to_string();
 
         // Generate attributes
@@ -238,27 +733,7 @@ impl From<Element> for proc_macro2::TokenStream {
 
         // Generate children if any
         if !val.children.is_empty() {
-            let mut children = Vec::new();
-            for child in &val.children {
-                let child_code: proc_macro2::TokenStream = match child.as_ref() {
-                    Node::Element(element) => element.clone().into(),
-                    Node::Text(text) => {
-                        quote! {
-                            html!("span", {
-                                .text(#text)
-                            })
-                        }
-                    }
-                    Node::Expression(expr) => {
-                        quote! {
-                            html!("span", {
-                                .text_signal((#expr).value().map(|x| x.to_string()))
-                            })
-                        }
-                    }
-                };
-                children.push(child_code);
-            }
+            let children = val.children.iter().flat_map(|child| child_code(child)).collect::<Vec<_>>();
 
             let children_method = quote! {
                 .children(&mut [
@@ -276,3 +751,151 @@ impl From<Element> for proc_macro2::TokenStream {
         }
     }
 }
+
+fn child_code(child: &Node) -> Vec<proc_macro2::TokenStream> {
+    use quote::quote;
+
+    match child {
+        Node::Element(element) => vec![element.clone().into()],
+        // Fragments have no node of their own, their children are spliced into the parent
+        Node::Fragment(fragment) => fragment.children.iter().flat_map(|child| child_code(child)).collect(),
+        Node::Text(text) => vec![quote! {
+            html!("span", {
+                .text(#text)
+            })
+        }],
+        Node::Expression(expr) => vec![quote! {
+            html!("span", {
+                .text_signal((#expr).value().map(|x| x.to_string()))
+            })
+        }],
+        Node::If(_) | Node::Match(_) => vec![quote! {
+            ::core::compile_error!("Conditionals are only supported by the rsx! macro")
+        }],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bare_prop_is_true() {
+        let prop = syn::parse_str::<Prop>("disabled").unwrap();
+        assert_eq!(prop.name, "disabled");
+        assert!(matches!(prop.value, Expr::Lit(syn::ExprLit { lit: syn::Lit::Bool(syn::LitBool { value: true, .. }), .. })));
+    }
+
+    #[test]
+    fn hyphenated_and_namespaced_names() {
+        let name = syn::parse_str::<Name>("data-user-id").unwrap();
+        assert_eq!(name, "data-user-id");
+        assert_eq!(name.namespace(), None);
+
+        let name = syn::parse_str::<Name>("xlink:href").unwrap();
+        assert_eq!(name.namespace(), Some(("xlink", "href")));
+        assert!(name.as_ident().is_none());
+
+        // Keywords are names too
+        assert_eq!(syn::parse_str::<Name>("type").unwrap(), "type");
+    }
+
+    #[test]
+    fn spreads_are_kept_apart_from_props() {
+        let Nodes(nodes) = syn::parse_str("<div {..attrs} id=\"x\" {..more} />").unwrap();
+        let [Node::Element(element)] = nodes.as_slice() else { panic!("expected one element") };
+        assert_eq!(element.spreads.len(), 2);
+        assert_eq!(element.props.len(), 1);
+    }
+
+    #[test]
+    fn bare_props_before_close() {
+        let Nodes(nodes) = syn::parse_str("<input disabled required />").unwrap();
+        let [Node::Element(element)] = nodes.as_slice() else { panic!("expected one element") };
+        let names = element.props.iter().map(|prop| prop.name.to_string()).collect::<Vec<_>>();
+        assert_eq!(names, ["disabled", "required"]);
+    }
+
+    #[test]
+    fn style_objects() {
+        let prop = syn::parse_str::<Prop>("style={{ color: color, \"margin-top\": \"4px\" !important, z-index: 1 }}").unwrap();
+        let style = prop.style.unwrap();
+        let properties = style.iter().map(|property| (property.name.value(), property.important)).collect::<Vec<_>>();
+        assert_eq!(properties, [("color".to_owned(), false), ("margin-top".to_owned(), true), ("z-index".to_owned(), false)]);
+    }
+
+    #[test]
+    fn style_blocks_are_expressions() {
+        let prop = syn::parse_str::<Prop>("style={{ let style = \"color: red\"; style }}").unwrap();
+        assert!(prop.style.is_none());
+        assert!(matches!(prop.value, Expr::Block(_)));
+
+        let prop = syn::parse_str::<Prop>("style={{}}").unwrap();
+        assert_eq!(prop.style.unwrap().len(), 0);
+    }
+
+    #[test]
+    fn style_property_without_value() {
+        let error = syn::parse_str::<Prop>("style={{ color: , display: \"none\" }}").err().unwrap();
+        assert_eq!(error.to_string(), "Expected a value for `color`");
+    }
+
+    // Each element's tag and whether it's marked as SVG, in document order
+    fn svg_tags(input: &str) -> Vec<(String, bool)> {
+        fn walk(node: &Node, tags: &mut Vec<(String, bool)>) {
+            match node {
+                Node::Element(element) => {
+                    tags.push((element.name.to_string(), element.svg));
+                    element.children.iter().for_each(|child| walk(child, tags));
+                }
+                Node::Fragment(fragment) => fragment.children.iter().for_each(|child| walk(child, tags)),
+                Node::If(if_node) => {
+                    if_node.branches.iter().flat_map(|(_, nodes)| nodes).for_each(|child| walk(child, tags))
+                }
+                _ => {}
+            }
+        }
+
+        let Nodes(nodes) = syn::parse_str(input).unwrap();
+        let mut tags = Vec::new();
+        nodes.iter().for_each(|node| walk(node, &mut tags));
+        tags
+    }
+
+    fn tags(tags: &[(&str, bool)]) -> Vec<(String, bool)> {
+        tags.iter().map(|(tag, svg)| (tag.to_string(), *svg)).collect()
+    }
+
+    #[test]
+    fn svg_and_everything_inside_it() {
+        assert_eq!(
+            svg_tags("<div><svg><g><a /><title /></g>{if open { <path /> }}</svg></div>"),
+            tags(&[("div", false), ("svg", true), ("g", true), ("a", true), ("title", true), ("path", true)])
+        );
+    }
+
+    #[test]
+    fn foreign_object_children_are_html() {
+        assert_eq!(
+            svg_tags("<svg><foreignObject><p><a /><svg><rect /></svg></p></foreignObject></svg>"),
+            tags(&[("svg", true), ("foreignObject", true), ("p", false), ("a", false), ("svg", true), ("rect", true)])
+        );
+    }
+
+    #[test]
+    fn svg_only_tags_are_svg_anywhere() {
+        assert_eq!(
+            svg_tags("<><circle /><linearGradient><stop /></linearGradient><a /><title /></>"),
+            tags(&[("circle", true), ("linearGradient", true), ("stop", true), ("a", false), ("title", false)])
+        );
+    }
+
+    #[test]
+    fn components_pass_the_namespace_through() {
+        assert_eq!(
+            svg_tags("<svg><Icon><a /></Icon></svg>"),
+            tags(&[("svg", true), ("Icon", false), ("a", true)])
+        );
+        assert_eq!(svg_tags("<div><Icon><a /></Icon></div>"), tags(&[("div", false), ("Icon", false), ("a", false)]));
+    }
+}
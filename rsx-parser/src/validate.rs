@@ -0,0 +1,504 @@
+use {
+    crate::{
+        attr_props::{ATTR_MAP, DEPRECATED_ATTR_MAP},
+        attr_types::{AttrType, TARGET_KEYWORDS, attr_type},
+        is_known_element,
+        tokens::{Element, Node, Prop},
+    },
+    proc_macro2::Span,
+};
+
+/// Attributes every HTML element accepts
+/// https://html.spec.whatwg.org/multipage/dom.html#global-attributes
+pub const GLOBAL_ATTRS: &[&str] = &[
+    "accesskey",
+    "autocapitalize",
+    "autocorrect",
+    "autofocus",
+    "class",
+    "contenteditable",
+    "dir",
+    "draggable",
+    "enterkeyhint",
+    "exportparts",
+    "hidden",
+    "id",
+    "inert",
+    "inputmode",
+    "is",
+    "itemid",
+    "itemprop",
+    "itemref",
+    "itemscope",
+    "itemtype",
+    "lang",
+    "nonce",
+    "part",
+    "popover",
+    "role",
+    "slot",
+    "spellcheck",
+    "style",
+    "tabindex",
+    "title",
+    "translate",
+    "writingsuggestions",
+];
+
+/// Attributes added since the HTML 4 data in `mdn/attributes.json`, and the tags they apply to. Includes a few that
+/// HTML 4 deprecated and HTML 5 brought back, such as `<ol start>`.
+/// https://html.spec.whatwg.org/multipage/indices.html#attributes-3
+pub const HTML5_ATTRS: &[(&str, &[&str])] = &[
+    ("allow", &["iframe", "fencedframe"]),
+    ("allowfullscreen", &["iframe"]),
+    ("alpha", &["input"]),
+    ("as", &["link"]),
+    ("async", &["script"]),
+    ("autocomplete", &["form", "input", "select", "textarea"]),
+    ("autoplay", &["audio", "video"]),
+    ("blocking", &["link", "script", "style"]),
+    ("capture", &["input"]),
+    ("charset", &["meta", "script"]),
+    ("closedby", &["dialog"]),
+    ("colorspace", &["input"]),
+    ("command", &["button"]),
+    ("commandfor", &["button"]),
+    ("controls", &["audio", "video"]),
+    ("crossorigin", &["audio", "img", "link", "script", "video"]),
+    ("datetime", &["time"]),
+    ("decoding", &["img"]),
+    ("default", &["track"]),
+    ("dirname", &["input", "textarea"]),
+    ("disabled", &["fieldset", "link"]),
+    ("download", &["a", "area"]),
+    ("fetchpriority", &["img", "link", "script"]),
+    ("for", &["label", "output"]),
+    ("form", &["button", "fieldset", "input", "object", "output", "select", "textarea"]),
+    ("formaction", &["button", "input"]),
+    ("formenctype", &["button", "input"]),
+    ("formmethod", &["button", "input"]),
+    ("formnovalidate", &["button", "input"]),
+    ("formtarget", &["button", "input"]),
+    ("height", &["canvas", "embed", "fencedframe", "input", "source", "video"]),
+    ("high", &["meter"]),
+    ("href", &["base"]),
+    ("imagesizes", &["link"]),
+    ("imagesrcset", &["link"]),
+    ("integrity", &["link", "script"]),
+    ("kind", &["track"]),
+    ("label", &["track"]),
+    ("list", &["input"]),
+    ("loading", &["iframe", "img"]),
+    ("loop", &["audio", "video"]),
+    ("low", &["meter"]),
+    ("max", &["input", "meter", "progress"]),
+    ("maxlength", &["textarea"]),
+    ("media", &["meta", "source"]),
+    ("min", &["input", "meter"]),
+    ("minlength", &["input", "textarea"]),
+    ("multiple", &["input"]),
+    ("muted", &["audio", "video"]),
+    ("name", &["details", "fieldset", "output", "slot"]),
+    ("nomodule", &["script"]),
+    ("novalidate", &["form"]),
+    ("open", &["details", "dialog"]),
+    ("optimum", &["meter"]),
+    ("pattern", &["input"]),
+    ("ping", &["a", "area"]),
+    ("placeholder", &["input", "textarea"]),
+    ("playsinline", &["video"]),
+    ("popovertarget", &["button", "input"]),
+    ("popovertargetaction", &["button", "input"]),
+    ("poster", &["video"]),
+    ("preload", &["audio", "video"]),
+    ("referrerpolicy", &["a", "area", "iframe", "img", "link", "script"]),
+    ("rel", &["area", "form"]),
+    ("required", &["input", "select", "textarea"]),
+    ("reversed", &["ol"]),
+    ("sandbox", &["iframe"]),
+    ("shadowrootclonable", &["template"]),
+    ("shadowrootdelegatesfocus", &["template"]),
+    ("shadowrootmode", &["template"]),
+    ("shadowrootserializable", &["template"]),
+    ("sizes", &["img", "link", "source"]),
+    ("src", &["audio", "embed", "source", "track", "video"]),
+    ("srcdoc", &["iframe"]),
+    ("srclang", &["track"]),
+    ("srcset", &["img", "source"]),
+    ("start", &["ol"]),
+    ("step", &["input"]),
+    ("target", &["form"]),
+    ("type", &["embed", "ol", "source"]),
+    ("value", &["data", "li", "meter", "output", "progress"]),
+    ("width", &["canvas", "embed", "fencedframe", "input", "source", "video"]),
+    ("wrap", &["textarea"]),
+    ("xmlns", &["html"]),
+];
+
+/// Elements that can't have children
+/// https://html.spec.whatwg.org/multipage/syntax.html#void-elements
+pub const VOID_ELEMENTS: &[&str] =
+    &["area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source", "track", "wbr"];
+
+/// Elements the HTML spec marks as obsolete, and what to use instead
+/// https://html.spec.whatwg.org/multipage/obsolete.html#non-conforming-features
+pub const OBSOLETE_ELEMENTS: &[(&str, &str)] = &[
+    ("acronym", "use `<abbr>`"),
+    ("big", "use CSS `font-size`"),
+    ("center", "use CSS `text-align` or `margin: auto`"),
+    ("dir", "use `<ul>`"),
+    ("font", "use CSS"),
+    ("frame", "use `<iframe>`"),
+    ("frameset", "use `<iframe>`"),
+    ("marquee", "use CSS animations"),
+    ("nobr", "use CSS `white-space: nowrap`"),
+    ("noembed", "use `<object>` with fallback content"),
+    ("noframes", "use `<iframe>`"),
+    ("param", "use `<object data>`"),
+    ("plaintext", "use `<pre>`"),
+    ("rb", "put the text directly in `<ruby>`"),
+    ("rtc", "use `<rt>`"),
+    ("strike", "use `<s>` or `<del>`"),
+    ("tt", "use `<code>`, `<kbd>` or `<samp>`"),
+    ("xmp", "use `<pre>` or `<code>`"),
+];
+
+/// The root of MathML markup, whose tags and attributes aren't checked
+const MATHML: &str = "math";
+
+/// Whether `tag` accepts the `attr` attribute, according to `mdn/attributes.json` and [`HTML5_ATTRS`]
+pub fn is_known_attr(tag: &str, attr: &str) -> bool {
+    GLOBAL_ATTRS.contains(&attr)
+        || ATTR_MAP.iter().chain(HTML5_ATTRS).any(|(name, tags)| {
+            // HTML 4 had a few attributes on "all elements but ..." that are global now, e.g. `class` and `id`
+            *name == attr && (tags.contains(&tag) || tags.first().is_some_and(|tag| tag.starts_with("all elements")))
+        })
+}
+
+/// Whether `attr` is deprecated on `tag`, according to `mdn/attributes.json`
+pub fn is_deprecated_attr(tag: &str, attr: &str) -> bool {
+    let deprecated = DEPRECATED_ATTR_MAP.iter().any(|(name, tags)| *name == attr && tags.contains(&tag));
+    deprecated && !HTML5_ATTRS.iter().any(|(name, tags)| *name == attr && tags.contains(&tag))
+}
+
+/// A diagnostic `rsx!` shows as a warning rather than an error
+pub struct Warning {
+    /// What kind of warning this is, e.g. `unknown_attribute` or `obsolete_element`
+    pub kind: &'static str,
+    pub span: Span,
+    pub message: String,
+}
+
+/// Checks the elements in `nodes`:
+/// - Lowercase tags must be known HTML elements, or custom elements with a `-` in their name
+/// - Tags inside `<svg>` must be known SVG elements
+/// - Void elements such as `<input>` and `<br>` can't have children
+/// - Literal values must suit the attribute's type, e.g. `type="txt"` or `colspan="two"` are errors
+/// - Attributes the element doesn't accept, obsolete elements and deprecated attributes are warned about
+///
+/// Components, custom elements, SVG attributes and anything inside `<math>` are left alone. The HTML inside an SVG
+/// `<foreignObject>` is checked as usual.
+pub fn validate(nodes: &[Node]) -> syn::Result<Vec<Warning>> {
+    let mut errors = Vec::<syn::Error>::new();
+    let mut warnings = Vec::new();
+    nodes.iter().for_each(|node| validate_node(node, &mut errors, &mut warnings));
+
+    match errors.into_iter().reduce(|mut errors, error| {
+        errors.combine(error);
+        errors
+    }) {
+        Some(errors) => Err(errors),
+        None => Ok(warnings),
+    }
+}
+
+fn validate_node(node: &Node, errors: &mut Vec<syn::Error>, warnings: &mut Vec<Warning>) {
+    let children = match node {
+        Node::Element(element) => {
+            let tag = element.name.to_string();
+            if tag == MATHML {
+                return;
+            }
+
+            match element.svg {
+                true => validate_svg_element(element, &tag, errors),
+                false => validate_element(element, &tag, errors, warnings),
+            }
+            element.children.iter().map(Box::as_ref).collect()
+        }
+        Node::Fragment(fragment) => fragment.children.iter().map(Box::as_ref).collect(),
+        Node::If(if_node) => {
+            if_node.branches.iter().map(|(_, nodes)| nodes).chain(&if_node.otherwise).flatten().collect()
+        }
+        Node::Match(match_node) => match_node.arms.iter().flat_map(|arm| &arm.body).collect(),
+        Node::Text(_) | Node::Expression(_) => Vec::new(),
+    };
+
+    children.into_iter().for_each(|child| validate_node(child, errors, warnings));
+}
+
+fn validate_element(element: &Element, tag: &str, errors: &mut Vec<syn::Error>, warnings: &mut Vec<Warning>) {
+    // Components, and custom elements which can take any attribute
+    if tag.starts_with(|c: char| !c.is_ascii_lowercase()) || tag.contains('-') {
+        return;
+    }
+
+    if !is_known_element(tag) {
+        let message = match suggest(tag, crate::ELEMENTS.iter().map(|(tag, _)| *tag)) {
+            Some(suggestion) => format!("Unknown element `<{tag}>`, did you mean `<{suggestion}>`?"),
+            None => format!("Unknown element `<{tag}>`, custom elements need a `-` in their name"),
+        };
+        errors.push(syn::Error::new_spanned(&element.name, message));
+        return;
+    }
+
+    if VOID_ELEMENTS.contains(&tag) && !element.children.is_empty() {
+        errors.push(syn::Error::new_spanned(
+            &element.name,
+            format!("`<{tag}>` is a void element and can't have children, close it with `<{tag} />`"),
+        ));
+    }
+
+    if let Some((_, instead)) = OBSOLETE_ELEMENTS.iter().find(|(obsolete, _)| *obsolete == tag) {
+        let message = format!("`<{tag}>` is obsolete, {instead}");
+        warnings.push(Warning { kind: "obsolete_element", span: element.name.span(), message });
+    }
+
+    for prop in &element.props {
+        if let Some(warning) = validate_attr(tag, prop) {
+            warnings.push(warning);
+        }
+        if let Err(error) = validate_value(tag, prop) {
+            errors.push(error);
+        }
+    }
+}
+
+fn validate_svg_element(element: &Element, tag: &str, errors: &mut Vec<syn::Error>) {
+    if tag.contains('-') || crate::is_svg_element(tag) {
+        return;
+    }
+
+    let message = match suggest(tag, crate::SVG_ELEMENTS.iter().map(|(tag, _)| *tag)) {
+        Some(suggestion) => format!("Unknown SVG element `<{tag}>`, did you mean `<{suggestion}>`?"),
+        None => format!("Unknown SVG element `<{tag}>`"),
+    };
+    errors.push(syn::Error::new_spanned(&element.name, message));
+}
+
+// Literal values have to match the attribute's type, expressions are checked by `rustsx::attr::AttrValue` instead
+fn validate_value(tag: &str, prop: &Prop) -> syn::Result<()> {
+    let syn::Expr::Lit(syn::ExprLit { lit, .. }) = &prop.value else {
+        return Ok(());
+    };
+    let value = match lit {
+        syn::Lit::Str(lit) => lit.value(),
+        syn::Lit::Int(lit) => lit.base10_digits().to_string(),
+        syn::Lit::Float(lit) => lit.base10_digits().to_string(),
+        syn::Lit::Bool(lit) => lit.value.to_string(),
+        _ => return Ok(()),
+    };
+    let attr = prop.name.to_string();
+
+    match attr_type(tag, &attr) {
+        AttrType::Number if matches!(lit, syn::Lit::Bool(_)) || value.trim().parse::<f64>().is_err() => {
+            Err(syn::Error::new_spanned(lit, format!("`{attr}` on `<{tag}>` takes a number")))
+        }
+        AttrType::Enum(keywords) if !keywords.contains(&value.to_lowercase().as_str()) => {
+            Err(syn::Error::new_spanned(lit, invalid_keyword(&attr, tag, &value, keywords)))
+        }
+        AttrType::Target if value.starts_with('_') && !TARGET_KEYWORDS.contains(&value.to_lowercase().as_str()) => {
+            Err(syn::Error::new_spanned(lit, invalid_keyword(&attr, tag, &value, TARGET_KEYWORDS)))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn invalid_keyword(attr: &str, tag: &str, value: &str, keywords: &[&str]) -> String {
+    match suggest(&value.to_lowercase(), keywords.iter().copied()) {
+        Some(suggestion) => format!("`{value}` isn't a valid `{attr}` on `<{tag}>`, did you mean `{suggestion}`?"),
+        None => {
+            let keywords = keywords.iter().map(|keyword| format!("`{keyword}`")).collect::<Vec<_>>().join(", ");
+            format!("`{value}` isn't a valid `{attr}` on `<{tag}>`, expected one of {keywords}")
+        }
+    }
+}
+
+fn validate_attr(tag: &str, prop: &Prop) -> Option<Warning> {
+    let attr = prop.name.to_string();
+
+    // Events, directives such as `bind:value` and `class:active`, namespaced attributes, `ref`, data / ARIA attributes
+    // and DOM properties (e.g. `innerHTML`) are handled elsewhere or can't be known here
+    let unchecked = attr.starts_with("on")
+        || attr.contains(':')
+        || attr == "ref"
+        || attr.starts_with("data-")
+        || attr.starts_with("aria-")
+        || attr.contains(|c: char| c.is_ascii_uppercase());
+    if unchecked {
+        return None;
+    }
+
+    if is_known_attr(tag, &attr) {
+        return is_deprecated_attr(tag, &attr).then(|| Warning {
+            kind: "deprecated_attribute",
+            span: prop.name.span(),
+            message: format!("`{attr}` is deprecated on `<{tag}>`"),
+        });
+    }
+
+    let candidates = GLOBAL_ATTRS.iter().copied().chain(
+        ATTR_MAP.iter().chain(HTML5_ATTRS).filter(|(_, tags)| tags.contains(&tag)).map(|(name, _)| *name),
+    );
+    let message = match suggest(&attr, candidates) {
+        Some(suggestion) => format!("Unknown attribute `{attr}` on `<{tag}>`, did you mean `{suggestion}`?"),
+        None => format!("Unknown attribute `{attr}` on `<{tag}>`"),
+    };
+    Some(Warning { kind: "unknown_attribute", span: prop.name.span(), message })
+}
+
+/// The closest of `candidates` to a misspelt `name`, if any are close enough
+pub fn suggest<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let max_distance = (name.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+// Edit distance counting swapped neighbours as one edit, so `dvi` is as close to `div` as `dix`
+fn edit_distance(a: &str, b: &str) -> usize {
+    let (a, b) = (a.chars().collect::<Vec<_>>(), b.chars().collect::<Vec<_>>());
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    (0..=a.len()).for_each(|i| distances[i][0] = i);
+    (0..=b.len()).for_each(|j| distances[0][j] = j);
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance =
+                (distances[i - 1][j] + 1).min(distances[i][j - 1] + 1).min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::tokens::Nodes};
+
+    fn check(input: &str) -> syn::Result<Vec<Warning>> {
+        let Nodes(nodes) = syn::parse_str(input).unwrap();
+        validate(&nodes)
+    }
+
+    fn warnings(input: &str) -> Vec<(&'static str, String)> {
+        check(input).unwrap().into_iter().map(|warning| (warning.kind, warning.message)).collect()
+    }
+
+    fn errors(input: &str) -> Vec<String> {
+        check(input).err().unwrap().into_iter().map(|error| error.to_string()).collect()
+    }
+
+    #[test]
+    fn valid_documents_have_no_warnings() {
+        let document = r#"
+            <html lang="en">
+                <head>
+                    <meta charset="utf-8" />
+                    <meta name="viewport" content="width=device-width" />
+                    <link rel="stylesheet" href="style.css" />
+                    <script src="app.js" charset="utf-8" async defer></script>
+                </head>
+                <body>
+                    <form action="/search" method="get" novalidate>
+                        <label for="query">"Search"</label>
+                        <input id="query" type="search" name="q" placeholder="Search" required />
+                        <input type="file" name="photos" accept="image/*" multiple />
+                        <input type="file" name="selfie" accept="image/*" capture="user" />
+                        <input type="file" name="scan" capture />
+                        <output for="query" name="count"></output>
+                        <select name="sort"><option value="new" selected>"Newest"</option></select>
+                        <button type="submit" disabled={false}>"Go"</button>
+                    </form>
+                    <ol start={3} reversed><li value={3}>"Three"</li></ol>
+                    <a href="/" target="_blank" rel="noopener" data-id="1" aria-label="Home" onclick={handler}>"Home"</a>
+                    <img src="logo.png" alt="Logo" width={32} height={32} loading="lazy" />
+                    <my-element anything="goes" />
+                    <Card title="Component" />
+                    <svg viewBox="0 0 24 24"><circle cx="12" cy="12" r="4" /></svg>
+                    <math><mi>"x"</mi></math>
+                </body>
+            </html>
+        "#;
+        assert_eq!(warnings(document), []);
+    }
+
+    #[test]
+    fn unknown_elements() {
+        assert_eq!(errors("<dvi />"), ["Unknown element `<dvi>`, did you mean `<div>`?"]);
+        assert_eq!(errors("<widget />"), ["Unknown element `<widget>`, custom elements need a `-` in their name"]);
+        assert_eq!(errors("<svg><cirle /></svg>"), ["Unknown SVG element `<cirle>`, did you mean `<circle>`?"]);
+    }
+
+    #[test]
+    fn html_inside_foreign_object() {
+        assert!(check("<svg><foreignObject><div /></foreignObject></svg>").is_ok());
+        assert_eq!(errors("<svg><foreignObject><dvi /></foreignObject></svg>").len(), 1);
+    }
+
+    #[test]
+    fn void_elements_have_no_children() {
+        assert_eq!(errors("<br>\"x\"</br>"), [
+            "`<br>` is a void element and can't have children, close it with `<br />`"
+        ]);
+    }
+
+    #[test]
+    fn all_errors_are_reported() {
+        assert_eq!(errors("<div><dvi /><input>\"x\"</input><td colspan=\"two\" /></div>"), [
+            "Unknown element `<dvi>`, did you mean `<div>`?",
+            "`<input>` is a void element and can't have children, close it with `<input />`",
+            "`colspan` on `<td>` takes a number",
+        ]);
+    }
+
+    #[test]
+    fn literal_values() {
+        assert_eq!(errors("<input type=\"txt\" />"), ["`txt` isn't a valid `type` on `<input>`, did you mean `text`?"]);
+        assert_eq!(errors("<a target=\"_new\" />").len(), 1);
+        assert!(check("<a target=\"frame\" />").is_ok());
+        assert!(check("<input type=\"EMAIL\" />").is_ok());
+    }
+
+    #[test]
+    fn literal_numbers() {
+        assert!(check("<td colspan={2} rowspan=\"3\" /><meter value={0.5} /><input maxlength=\" 10 \" />").is_ok());
+        assert_eq!(errors("<td colspan={true} />"), ["`colspan` on `<td>` takes a number"]);
+        assert_eq!(errors("<progress max=\"lots\" />"), ["`max` on `<progress>` takes a number"]);
+        assert!(check("<td colspan={count} />").is_ok());
+    }
+
+    #[test]
+    fn warnings_have_kinds() {
+        assert_eq!(warnings("<center />"), [(
+            "obsolete_element",
+            "`<center>` is obsolete, use CSS `text-align` or `margin: auto`".to_owned()
+        )]);
+        assert_eq!(warnings("<div hreff=\"x\" />"), [(
+            "unknown_attribute",
+            "Unknown attribute `hreff` on `<div>`".to_owned()
+        )]);
+        assert_eq!(warnings("<td bgcolor=\"red\" />"), [(
+            "deprecated_attribute",
+            "`bgcolor` is deprecated on `<td>`".to_owned()
+        )]);
+    }
+}
@@ -0,0 +1,188 @@
+use {
+    crate::reactive::{MutableKind, MutableLike, Reactive, SignalKind, ValueKind},
+    futures_signals::signal::{Always, Signal, SignalExt},
+    rsx_dominator::DomBuilder,
+    rsx_parser::attr_props,
+    wasm_bindgen::JsValue,
+    web_sys::Element,
+};
+
+/// Adds (`true`) or leaves off (`false`) a boolean attribute such as `disabled` or `required`.
+/// Signals add and remove the attribute as they change, rather than writing `"false"`.
+///
+/// Ones with a DOM property of the same name, such as `checked`, `selected` or `muted`, are set as properties instead,
+/// so they follow the live state rather than only the initial one.
+pub fn boolean<A, S>(dom: DomBuilder<A>, name: &'static str, value: Reactive<bool, S>) -> DomBuilder<A>
+where
+    A: AsRef<Element> + AsRef<JsValue>,
+    S: Signal<Item = bool> + 'static,
+{
+    if attr_props::should_set_as_prop(&dom, name, &JsValue::TRUE) {
+        return match value {
+            Reactive::Value(value) => dom.prop(name, value),
+            Reactive::Signal(signal) => dom.prop_signal(name, signal),
+        };
+    }
+
+    match value {
+        Reactive::Value(true) => dom.attr(name, ""),
+        Reactive::Value(false) => dom,
+        Reactive::Signal(signal) => dom.attr_signal(name, signal.map(|v| v.then_some(""))),
+    }
+}
+
+/// A value that can toggle a boolean attribute, see [`boolean`]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be used as a boolean attribute value",
+    note = "boolean attributes take a `bool`, or a `Mutable` or signal of one, and a string literal such as \
+            `disabled=\"disabled\"` is set as written"
+)]
+pub trait BooleanValue {
+    fn into_bool(self) -> bool;
+}
+
+impl BooleanValue for bool {
+    #[inline]
+    fn into_bool(self) -> bool { self }
+}
+
+/// Kinds of attribute value, picked by `rsx!` from the attribute's type in the MDN data (see
+/// `rsx_parser::attr_types`). Literals are checked at compile time and always set as `Text`.
+pub mod kinds {
+    /// Text, numbers, bools or an `Option` of them, e.g. `title` or `class`
+    pub struct Text;
+    /// Integers and floats or an `Option` of them, e.g. `colspan` or `maxlength`
+    pub struct Number;
+    /// A `&str`, `String`, `web_sys::Url` or an `Option` of them, e.g. `href` or `src`
+    pub struct Url;
+}
+
+/// A value that can be set on an attribute of the kind `K`, see [`kinds`]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be used as a `{K}` attribute value",
+    note = "number attributes take integers or floats, URL attributes a `&str`, `String` or `web_sys::Url`, and any \
+            of them can be an `Option` to leave the attribute off"
+)]
+pub trait AttrValue<K> {
+    fn into_attr(self) -> JsValue;
+}
+
+impl<T: Into<JsValue>> AttrValue<kinds::Text> for T {
+    #[inline]
+    fn into_attr(self) -> JsValue { self.into() }
+}
+
+macro_rules! attr_value {
+    ($kind:ident: $($ty:ty),* => |$value:ident| $into:expr) => {$(
+        impl AttrValue<kinds::$kind> for $ty {
+            #[inline]
+            fn into_attr(self) -> JsValue {
+                let $value = self;
+                $into
+            }
+        }
+
+        impl AttrValue<kinds::$kind> for Option<$ty> {
+            #[inline]
+            fn into_attr(self) -> JsValue {
+                match self {
+                    Some($value) => $into,
+                    None => JsValue::UNDEFINED,
+                }
+            }
+        }
+    )*};
+}
+
+// As `f64`s, so 64 and 128 bit integers don't become `BigInt`s
+attr_value!(Number: i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64 => |value| {
+    JsValue::from_f64(value as f64)
+});
+attr_value!(Url: &str, String, &String => |value| JsValue::from(value));
+attr_value!(Url: web_sys::Url, &web_sys::Url => |value| JsValue::from_str(&value.href()));
+
+// Sets an attribute or DOM property for each kind of value sorted by `rsx!`, see `attr_props::apply`. Plain values
+// are set once, `Mutable`s and signals are bound. `None` leaves the attribute off, and removes it again from a signal.
+
+impl ValueKind {
+    #[inline]
+    pub fn attr<K, A, T>(self, dom: DomBuilder<A>, name: &'static str, value: T) -> DomBuilder<A>
+    where
+        A: AsRef<Element> + AsRef<JsValue>,
+        T: AttrValue<K>,
+    {
+        attr_props::apply(dom, name, value.into_attr())
+    }
+
+    #[inline]
+    pub fn boolean<A, T>(self, dom: DomBuilder<A>, name: &'static str, value: T) -> DomBuilder<A>
+    where
+        A: AsRef<Element> + AsRef<JsValue>,
+        T: BooleanValue,
+    {
+        boolean(dom, name, Reactive::<_, Always<bool>>::Value(value.into_bool()))
+    }
+}
+
+impl MutableKind {
+    #[inline]
+    pub fn attr<K: 'static, A, M>(self, dom: DomBuilder<A>, name: &'static str, mutable: M) -> DomBuilder<A>
+    where
+        A: AsRef<Element> + AsRef<JsValue>,
+        M: MutableLike,
+        M::Item: AttrValue<K> + 'static,
+    {
+        attr_props::bind(dom, name, mutable.to_signal_cloned().map(AttrValue::into_attr))
+    }
+
+    #[inline]
+    pub fn boolean<A, M>(self, dom: DomBuilder<A>, name: &'static str, mutable: M) -> DomBuilder<A>
+    where
+        A: AsRef<Element> + AsRef<JsValue>,
+        M: MutableLike,
+        M::Item: BooleanValue + 'static,
+    {
+        boolean(dom, name, Reactive::Signal(mutable.to_signal_cloned().map(BooleanValue::into_bool)))
+    }
+}
+
+impl SignalKind {
+    #[inline]
+    pub fn attr<K: 'static, A, S>(self, dom: DomBuilder<A>, name: &'static str, signal: S) -> DomBuilder<A>
+    where
+        A: AsRef<Element> + AsRef<JsValue>,
+        S: Signal + 'static,
+        S::Item: AttrValue<K>,
+    {
+        attr_props::bind(dom, name, signal.map(AttrValue::into_attr))
+    }
+
+    #[inline]
+    pub fn boolean<A, S>(self, dom: DomBuilder<A>, name: &'static str, signal: S) -> DomBuilder<A>
+    where
+        A: AsRef<Element> + AsRef<JsValue>,
+        S: Signal + 'static,
+        S::Item: BooleanValue,
+    {
+        boolean(dom, name, Reactive::Signal(signal.map(BooleanValue::into_bool)))
+    }
+}
+
+/// Something that can be spread onto an element with `<div {..value} />`.
+///
+/// Implemented for any iterable of `(name, value)` pairs, e.g. a `Vec<(&str, String)>` or `HashMap<String, String>`.
+/// Implement it for your own props structs to forward them onto an inner element.
+pub trait Spread {
+    fn spread<A: AsRef<Element>>(self, dom: DomBuilder<A>) -> DomBuilder<A>;
+}
+
+impl<I, K, V> Spread for I
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    fn spread<A: AsRef<Element>>(self, dom: DomBuilder<A>) -> DomBuilder<A> {
+        self.into_iter().fold(dom, |dom, (name, value)| dom.attr(name.as_ref(), value.as_ref()))
+    }
+}
@@ -0,0 +1,154 @@
+use {
+    futures_signals::signal::{Mutable, SignalExt},
+    js_sys::Reflect,
+    rsx_dominator::{DomBuilder, events},
+    std::{cell::Cell, future::ready, rc::Rc},
+    wasm_bindgen::JsValue,
+    web_sys::{Element, EventTarget},
+};
+
+/// A value `bind:value` and `bind:group` can show in a form control, and read back from it.
+///
+/// Implemented for `String` and the numeric types, implement it for your own types (e.g. an enum of `<option>`s).
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be bound to a form control",
+    note = "implement `rustsx::bind::BindValue` for it, to say how it's shown in and read back from the control"
+)]
+pub trait BindValue: Clone + PartialEq + 'static {
+    fn to_input(&self) -> String;
+
+    /// `None` if the input can't be read as this type (yet), e.g. `"1e"` for a number
+    fn from_input(input: &str) -> Option<Self>;
+}
+
+impl BindValue for String {
+    #[inline]
+    fn to_input(&self) -> String { self.clone() }
+
+    #[inline]
+    fn from_input(input: &str) -> Option<Self> { Some(input.to_owned()) }
+}
+
+macro_rules! bind_number {
+    ($($ty:ty),*) => {$(
+        impl BindValue for $ty {
+            #[inline]
+            fn to_input(&self) -> String { self.to_string() }
+
+            #[inline]
+            fn from_input(input: &str) -> Option<Self> { input.trim().parse().ok() }
+        }
+    )*};
+}
+
+bind_number!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+fn get(el: &Element, key: &str) -> JsValue { Reflect::get(el, &JsValue::from_str(key)).unwrap_or(JsValue::UNDEFINED) }
+
+fn set(el: &Element, key: &str, value: JsValue) { let _ = Reflect::set(el, &JsValue::from_str(key), &value); }
+
+/// `bind:value={mutable}` on an `<input>`, `<textarea>` or `<select>`.
+///
+/// The control shows the mutable's value, and writes back to it as it's edited. Text being composed with an IME is
+/// written back once it's finished, and numbers only while what's typed parses.
+pub fn value<A, T>(dom: DomBuilder<A>, mutable: Mutable<T>) -> DomBuilder<A>
+where
+    A: AsRef<Element> + AsRef<EventTarget>,
+    T: BindValue,
+{
+    let el: Element = AsRef::<Element>::as_ref(&dom).clone();
+    let composing = Rc::new(Cell::new(false));
+
+    let read = {
+        let el = el.clone();
+        let mutable = mutable.clone();
+        move || {
+            if let Some(value) = get(&el, "value").as_string().and_then(|value| T::from_input(&value)) {
+                mutable.set_neq(value);
+            }
+        }
+    };
+
+    // `<select>`s settle on `change`, other controls update on every `input`
+    let dom = if el.tag_name() == "SELECT" {
+        let read = read.clone();
+        dom.event(move |_: events::Change| read())
+    } else {
+        let read = read.clone();
+        let composing = composing.clone();
+        dom.event(move |_: events::Input| {
+            if !composing.get() {
+                read();
+            }
+        })
+    };
+
+    dom.custom_event("compositionstart", {
+        let composing = composing.clone();
+        move |_| composing.set(true)
+    })
+    .custom_event("compositionend", {
+        let composing = composing.clone();
+        move |_| {
+            composing.set(false);
+            read();
+        }
+    })
+    .future(mutable.signal_cloned().for_each(move |value| {
+        // Leave values that came from the control itself, so `1.` isn't rewritten as `1` and the cursor stays put
+        let current = get(&el, "value").as_string().and_then(|current| T::from_input(&current));
+        if !composing.get() && current.as_ref() != Some(&value) {
+            set(&el, "value", value.to_input().into());
+        }
+        ready(())
+    }))
+}
+
+/// `bind:checked={mutable}` on a checkbox
+pub fn checked<A>(dom: DomBuilder<A>, mutable: Mutable<bool>) -> DomBuilder<A>
+where
+    A: AsRef<Element> + AsRef<EventTarget>,
+{
+    let el: Element = AsRef::<Element>::as_ref(&dom).clone();
+
+    dom.event({
+        let el = el.clone();
+        let mutable = mutable.clone();
+        move |_: events::Change| mutable.set_neq(get(&el, "checked").as_bool().unwrap_or(false))
+    })
+    .future(mutable.signal().for_each(move |checked| {
+        set(&el, "checked", checked.into());
+        ready(())
+    }))
+}
+
+/// `bind:group={mutable}` on radio buttons. The one whose `value` matches the mutable is checked, and checking one sets
+/// the mutable to its `value`.
+pub fn group<A, T>(dom: DomBuilder<A>, mutable: Mutable<T>) -> DomBuilder<A>
+where
+    A: AsRef<Element> + AsRef<EventTarget>,
+    T: BindValue,
+{
+    let el: Element = AsRef::<Element>::as_ref(&dom).clone();
+    let value = {
+        let el = el.clone();
+        move || get(&el, "value").as_string().and_then(|value| T::from_input(&value))
+    };
+
+    dom.event({
+        let el = el.clone();
+        let value = value.clone();
+        let mutable = mutable.clone();
+        move |_: events::Change| {
+            if get(&el, "checked").as_bool().unwrap_or(false)
+                && let Some(value) = value()
+            {
+                mutable.set_neq(value);
+            }
+        }
+    })
+    .future(mutable.signal_cloned().for_each(move |selected| {
+        set(&el, "checked", (value().as_ref() == Some(&selected)).into());
+        ready(())
+    }))
+}
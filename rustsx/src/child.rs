@@ -0,0 +1,288 @@
+use {
+    crate::{component::Children, reactive::MaybeSignal},
+    futures_signals::{
+        signal::{Mutable, ReadOnlyMutable, Signal, SignalExt},
+        signal_vec::SignalVec,
+    },
+    rsx_dominator::{Dom, DomBuilder, Fragment, FragmentBuilder, traits::AsStr},
+    std::fmt::Display,
+    web_sys::Node,
+};
+
+/// Something `{expr}` children can be added to, an element or a component's [`Children`]
+pub trait Parent: Sized {
+    fn child(self, child: Dom) -> Self;
+    fn text(self, text: &str) -> Self;
+    fn text_signal<A: AsStr, S: Signal<Item = A> + 'static>(self, text: S) -> Self;
+    fn child_signal<S: Signal<Item = Option<Dom>> + 'static>(self, child: S) -> Self;
+    fn children_signal_vec<S: SignalVec<Item = Dom> + 'static>(self, children: S) -> Self;
+    fn fragment<F: Fragment>(self, fragment: &F) -> Self;
+}
+
+impl<A: AsRef<Node>> Parent for DomBuilder<A> {
+    #[inline]
+    fn child(self, child: Dom) -> Self { self.child(child) }
+
+    #[inline]
+    fn text(self, text: &str) -> Self { self.text(text) }
+
+    #[inline]
+    fn text_signal<B: AsStr, S: Signal<Item = B> + 'static>(self, text: S) -> Self { self.text_signal(text) }
+
+    #[inline]
+    fn child_signal<S: Signal<Item = Option<Dom>> + 'static>(self, child: S) -> Self { self.child_signal(child) }
+
+    #[inline]
+    fn children_signal_vec<S: SignalVec<Item = Dom> + 'static>(self, children: S) -> Self {
+        self.children_signal_vec(children)
+    }
+
+    #[inline]
+    fn fragment<F: Fragment>(self, fragment: &F) -> Self { self.fragment(fragment) }
+}
+
+impl Parent for FragmentBuilder<'_> {
+    #[inline]
+    fn child(self, child: Dom) -> Self { self.child(child) }
+
+    #[inline]
+    fn text(self, text: &str) -> Self { self.text(text) }
+
+    #[inline]
+    fn text_signal<B: AsStr, S: Signal<Item = B> + 'static>(self, text: S) -> Self { self.text_signal(text) }
+
+    #[inline]
+    fn child_signal<S: Signal<Item = Option<Dom>> + 'static>(self, child: S) -> Self { self.child_signal(child) }
+
+    #[inline]
+    fn children_signal_vec<S: SignalVec<Item = Dom> + 'static>(self, children: S) -> Self {
+        self.children_signal_vec(children)
+    }
+
+    #[inline]
+    fn fragment<F: Fragment>(self, fragment: &F) -> Self { self.fragment(fragment) }
+}
+
+/// Something that can be written as a `{child}` in `rsx!`.
+///
+/// `rsx!` first sorts the expression with the `Via*` traits below, so `Mutable`s are borrowed rather than moved and
+/// signals end up in the right wrapper. Between them, children can be:
+/// - `Dom`, `&str`, `String`, numbers, `char`s and [`Children`]
+/// - `Option<Dom>`, `Vec<Dom>` or any other iterator of `Dom`s
+/// - `Mutable<T>`, `ReadOnlyMutable<T>` or [`MaybeSignal<T>`] where `T: Display`
+/// - Any `Signal` of `impl Display`, `Dom` or `Option<Dom>`
+/// - Any `SignalVec<Item = Dom>`
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be used as a child in `rsx!`",
+    note = "children can be text, numbers, `Dom`s, iterators of `Dom`s, `Mutable`s or signals, see `IntoChild`"
+)]
+pub trait IntoChild {
+    fn append_to<P: Parent>(self, parent: P) -> P;
+}
+
+/// Children that can stand on their own, without a parent element, e.g. at the root of `rsx!`
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` needs a parent element here",
+    note = "wrap it in an element, e.g. `<div>{{..}}</div>`"
+)]
+pub trait IntoDoms {
+    fn into_doms(self) -> Vec<Dom>;
+}
+
+impl IntoChild for Dom {
+    #[inline]
+    fn append_to<P: Parent>(self, parent: P) -> P { parent.child(self) }
+}
+
+impl IntoDoms for Dom {
+    #[inline]
+    fn into_doms(self) -> Vec<Dom> { vec![self] }
+}
+
+impl IntoChild for &Children {
+    #[inline]
+    fn append_to<P: Parent>(self, parent: P) -> P { parent.fragment(self) }
+}
+
+impl IntoChild for &str {
+    #[inline]
+    fn append_to<P: Parent>(self, parent: P) -> P { parent.text(self) }
+}
+
+impl IntoDoms for &str {
+    #[inline]
+    fn into_doms(self) -> Vec<Dom> { vec![Dom::text(self)] }
+}
+
+macro_rules! text_child {
+    ($($ty:ty),*) => {$(
+        impl IntoChild for $ty {
+            #[inline]
+            fn append_to<P: Parent>(self, parent: P) -> P { parent.text(&self.to_string()) }
+        }
+
+        impl IntoDoms for $ty {
+            #[inline]
+            fn into_doms(self) -> Vec<Dom> { vec![Dom::text(self)] }
+        }
+    )*};
+}
+
+text_child!(String, &String, char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// An iterator of `Dom`s, including `Option<Dom>` and `Vec<Dom>`
+#[doc(hidden)]
+pub struct IterChild<I>(pub I);
+
+impl<I: IntoIterator<Item = Dom>> IntoChild for IterChild<I> {
+    #[inline]
+    fn append_to<P: Parent>(self, parent: P) -> P { self.0.into_iter().fold(parent, P::child) }
+}
+
+impl<I: IntoIterator<Item = Dom>> IntoDoms for IterChild<I> {
+    #[inline]
+    fn into_doms(self) -> Vec<Dom> { self.0.into_iter().collect() }
+}
+
+/// A signal of text
+#[doc(hidden)]
+pub struct TextSignalChild<S>(pub S);
+
+impl<S> IntoChild for TextSignalChild<S>
+where
+    S: Signal + 'static,
+    S::Item: Display,
+{
+    #[inline]
+    fn append_to<P: Parent>(self, parent: P) -> P { parent.text_signal(self.0.map(|text| text.to_string())) }
+}
+
+impl<S> IntoDoms for TextSignalChild<S>
+where
+    S: Signal + 'static,
+    S::Item: Display,
+{
+    #[inline]
+    fn into_doms(self) -> Vec<Dom> { vec![Dom::text_signal(self.0.map(|text| text.to_string()))] }
+}
+
+/// A signal of a single `Dom`
+#[doc(hidden)]
+pub struct DomSignalChild<S>(pub S);
+
+impl<S: Signal<Item = Dom> + 'static> IntoChild for DomSignalChild<S> {
+    #[inline]
+    fn append_to<P: Parent>(self, parent: P) -> P { parent.child_signal(self.0.map(Some)) }
+}
+
+/// A signal of an optional `Dom`
+#[doc(hidden)]
+pub struct OptionSignalChild<S>(pub S);
+
+impl<S: Signal<Item = Option<Dom>> + 'static> IntoChild for OptionSignalChild<S> {
+    #[inline]
+    fn append_to<P: Parent>(self, parent: P) -> P { parent.child_signal(self.0) }
+}
+
+/// A signal vec of `Dom`s
+#[doc(hidden)]
+pub struct SignalVecChild<S>(pub S);
+
+impl<S: SignalVec<Item = Dom> + 'static> IntoChild for SignalVecChild<S> {
+    #[inline]
+    fn append_to<P: Parent>(self, parent: P) -> P { parent.children_signal_vec(self.0) }
+}
+
+// Picks the wrapper for a `{child}` expression, by method resolution on the expression itself. Each trait only
+// applies to one kind of type, and the `Mutable` ones take `&self` so those are borrowed rather than moved.
+
+#[doc(hidden)]
+pub trait ViaChild: IntoChild + Sized {
+    #[inline]
+    fn __rsx_child(self) -> Self { self }
+}
+
+impl<T: IntoChild> ViaChild for T {}
+
+#[doc(hidden)]
+pub trait ViaIter: IntoIterator<Item = Dom> + Sized {
+    #[inline]
+    fn __rsx_child(self) -> IterChild<Self> { IterChild(self) }
+}
+
+impl<I: IntoIterator<Item = Dom>> ViaIter for I {}
+
+#[doc(hidden)]
+pub trait ViaTextSignal: Signal + Sized {
+    #[inline]
+    fn __rsx_child(self) -> TextSignalChild<Self> { TextSignalChild(self) }
+}
+
+impl<S: Signal> ViaTextSignal for S where S::Item: Display {}
+
+#[doc(hidden)]
+pub trait ViaDomSignal: Signal<Item = Dom> + Sized {
+    #[inline]
+    fn __rsx_child(self) -> DomSignalChild<Self> { DomSignalChild(self) }
+}
+
+impl<S: Signal<Item = Dom>> ViaDomSignal for S {}
+
+#[doc(hidden)]
+pub trait ViaOptionSignal: Signal<Item = Option<Dom>> + Sized {
+    #[inline]
+    fn __rsx_child(self) -> OptionSignalChild<Self> { OptionSignalChild(self) }
+}
+
+impl<S: Signal<Item = Option<Dom>>> ViaOptionSignal for S {}
+
+#[doc(hidden)]
+pub trait ViaSignalVec: SignalVec<Item = Dom> + Sized {
+    #[inline]
+    fn __rsx_child(self) -> SignalVecChild<Self> { SignalVecChild(self) }
+}
+
+impl<S: SignalVec<Item = Dom>> ViaSignalVec for S {}
+
+#[doc(hidden)]
+pub trait ViaMutable {
+    type TextSignal;
+
+    fn __rsx_child(&self) -> TextSignalChild<Self::TextSignal>;
+}
+
+impl<T: Display + Clone + 'static> ViaMutable for Mutable<T> {
+    type TextSignal = futures_signals::signal::MutableSignalCloned<T>;
+
+    #[inline]
+    fn __rsx_child(&self) -> TextSignalChild<Self::TextSignal> { TextSignalChild(self.signal_cloned()) }
+}
+
+impl<T: Display + Clone + 'static> ViaMutable for ReadOnlyMutable<T> {
+    type TextSignal = futures_signals::signal::MutableSignalCloned<T>;
+
+    #[inline]
+    fn __rsx_child(&self) -> TextSignalChild<Self::TextSignal> { TextSignalChild(self.signal_cloned()) }
+}
+
+impl<T: Display + Clone + 'static> ViaMutable for MaybeSignal<T> {
+    type TextSignal = crate::reactive::MaybeSignalCloned<T>;
+
+    #[inline]
+    fn __rsx_child(&self) -> TextSignalChild<Self::TextSignal> { TextSignalChild(self.signal_cloned()) }
+}
+
+// Matches anything, but only after the ones above as it takes `&mut self`, so an unsupported child is reported as a
+// missing `IntoChild` rather than a missing method
+#[doc(hidden)]
+pub trait ViaUnsupported {
+    #[inline]
+    fn __rsx_child(&mut self) -> Self
+    where
+        Self: IntoChild + Sized,
+    {
+        unreachable!("`IntoChild` types are picked up by `ViaChild`")
+    }
+}
+
+impl<T: ?Sized> ViaUnsupported for T {}
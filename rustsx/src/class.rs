@@ -0,0 +1,83 @@
+use {
+    crate::reactive::{MutableKind, MutableLike, Reactive, SignalKind, ValueKind},
+    futures_signals::signal::{Signal, SignalExt},
+    rsx_dominator::{
+        DomBuilder,
+        traits::{AsStr, OptionStr},
+    },
+    std::future::ready,
+    web_sys::Element,
+};
+
+/// `class:name={value}` adds the class when `true` and leaves it off when `false`, signals toggle it as they change
+pub fn toggle<A, S>(dom: DomBuilder<A>, name: &'static str, value: Reactive<bool, S>) -> DomBuilder<A>
+where
+    A: AsRef<Element>,
+    S: Signal<Item = bool> + 'static,
+{
+    match value {
+        Reactive::Value(true) => dom.class(name),
+        Reactive::Value(false) => dom,
+        Reactive::Signal(signal) => dom.class_signal(name, signal),
+    }
+}
+
+// Adds the classes of one `class={..}` value, or one item of a `class={[..]}` list, for each kind of value sorted by
+// `rsx!`. Values can be a `&str`, a `String` or an `Option` of either, and may hold several space-separated classes.
+// The classes from a `Mutable` or signal are swapped out for the new ones as it changes, and `None` removes them.
+
+impl ValueKind {
+    #[inline]
+    pub fn class<A, B, T>(self, dom: DomBuilder<A>, value: T) -> DomBuilder<A>
+    where
+        A: AsRef<Element>,
+        B: AsStr,
+        T: OptionStr<Output = B>,
+    {
+        match value.into_option() {
+            Some(classes) => classes.with_str(|classes| classes.split_whitespace().fold(dom, DomBuilder::class)),
+            None => dom,
+        }
+    }
+}
+
+impl MutableKind {
+    #[inline]
+    pub fn class<A, B, M>(self, dom: DomBuilder<A>, mutable: M) -> DomBuilder<A>
+    where
+        A: AsRef<Element>,
+        B: AsStr,
+        M: MutableLike,
+        M::Item: OptionStr<Output = B> + 'static,
+    {
+        SignalKind.class(dom, mutable.to_signal_cloned())
+    }
+}
+
+impl SignalKind {
+    pub fn class<A, B, S>(self, dom: DomBuilder<A>, signal: S) -> DomBuilder<A>
+    where
+        A: AsRef<Element>,
+        B: AsStr,
+        S: Signal + 'static,
+        S::Item: OptionStr<Output = B>,
+    {
+        let classes = AsRef::<Element>::as_ref(&dom).class_list();
+        let mut current = Vec::<String>::new();
+
+        dom.future(signal.for_each(move |value| {
+            for class in current.drain(..) {
+                let _ = classes.remove_1(&class);
+            }
+
+            if let Some(value) = value.into_option() {
+                value.with_str(|value| current.extend(value.split_whitespace().map(str::to_owned)));
+                for class in &current {
+                    let _ = classes.add_1(class);
+                }
+            }
+
+            ready(())
+        }))
+    }
+}
@@ -0,0 +1,153 @@
+use {
+    crate::reactive::MaybeSignal,
+    futures_signals::signal::{Mutable, ReadOnlyMutable, Signal},
+    rsx_dominator::{Fragment, FragmentBuilder},
+    std::rc::Rc,
+};
+
+/// Markup nested inside a component, e.g. the `<p>` in `<Card><p>Hi</p></Card>`.
+///
+/// Take it as a `children: Children` parameter on a `#[component]` and place it with `<div>{children}</div>`.
+/// It can be placed more than once, each time renders a fresh copy.
+#[derive(Clone)]
+pub struct Children(Rc<dyn Fn(FragmentBuilder<'_>) -> FragmentBuilder<'_>>);
+
+impl Children {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(FragmentBuilder<'_>) -> FragmentBuilder<'_> + 'static,
+    {
+        Self(Rc::new(f))
+    }
+}
+
+impl Default for Children {
+    fn default() -> Self { Self::new(|dom| dom) }
+}
+
+impl Fragment for Children {
+    #[inline]
+    fn apply<'a>(&self, dom: FragmentBuilder<'a>) -> FragmentBuilder<'a> { (self.0)(dom) }
+}
+
+/// Implemented by `#[component]` for the props builders of components with a `children` parameter, which `rsx!`
+/// passes nested markup through so a component without one has a readable error
+#[diagnostic::on_unimplemented(
+    message = "this component doesn't accept children",
+    label = "children passed here",
+    note = "add a `children: Children` parameter to the component to accept nested markup"
+)]
+pub trait AcceptsChildren: Sized {
+    fn children(self, children: Children) -> Self;
+}
+
+/// A required prop that hasn't been given to a props builder yet
+#[doc(hidden)]
+pub struct Missing;
+
+/// A required prop that has been given to a props builder
+#[doc(hidden)]
+pub struct Set<T>(pub T);
+
+/// Converts what's written in `<Component prop={..} />` into the prop's type.
+///
+/// `rsx!` first sorts the value into a [`PropValue`], [`PropMutable`] or [`PropSignal`] (see
+/// [`Probe`](crate::reactive::Probe)), then picks the conversion from the prop's type:
+/// - Any prop takes a value of its own type, `Option<T>` props also take a bare `T`
+/// - `String` props (and `Mutable<String>` / `MaybeSignal<String>`) also take a `&str`
+/// - `Mutable<T>` props take a `T` or a `Mutable<T>`, `ReadOnlyMutable<T>` props either kind of mutable
+/// - [`MaybeSignal<T>`] props take a `T`, either kind of mutable, or any `Signal<Item = T>`
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be used as a `{T}` prop",
+    note = "props that change over time can be a `MaybeSignal<T>`, `Mutable<T>` or `ReadOnlyMutable<T>`"
+)]
+pub trait IntoProp<T> {
+    fn into_prop(self) -> T;
+}
+
+/// A prop given a plain value
+#[doc(hidden)]
+pub struct PropValue<T>(pub T);
+
+/// A prop given a `Mutable` or `ReadOnlyMutable`
+#[doc(hidden)]
+pub struct PropMutable<M>(pub M);
+
+/// A prop given a signal
+#[doc(hidden)]
+pub struct PropSignal<S>(pub S);
+
+impl<T> IntoProp<T> for PropValue<T> {
+    #[inline]
+    fn into_prop(self) -> T { self.0 }
+}
+
+impl<T> IntoProp<Option<T>> for PropValue<T> {
+    #[inline]
+    fn into_prop(self) -> Option<T> { Some(self.0) }
+}
+
+impl<T> IntoProp<Mutable<T>> for PropValue<T> {
+    #[inline]
+    fn into_prop(self) -> Mutable<T> { Mutable::new(self.0) }
+}
+
+impl<T> IntoProp<MaybeSignal<T>> for PropValue<T> {
+    #[inline]
+    fn into_prop(self) -> MaybeSignal<T> { MaybeSignal::Value(self.0) }
+}
+
+impl IntoProp<String> for PropValue<&str> {
+    #[inline]
+    fn into_prop(self) -> String { self.0.to_owned() }
+}
+
+impl IntoProp<Option<String>> for PropValue<&str> {
+    #[inline]
+    fn into_prop(self) -> Option<String> { Some(self.0.to_owned()) }
+}
+
+impl IntoProp<Mutable<String>> for PropValue<&str> {
+    #[inline]
+    fn into_prop(self) -> Mutable<String> { Mutable::new(self.0.to_owned()) }
+}
+
+impl IntoProp<MaybeSignal<String>> for PropValue<&str> {
+    #[inline]
+    fn into_prop(self) -> MaybeSignal<String> { MaybeSignal::Value(self.0.to_owned()) }
+}
+
+impl<T> IntoProp<Mutable<T>> for PropMutable<Mutable<T>> {
+    #[inline]
+    fn into_prop(self) -> Mutable<T> { self.0 }
+}
+
+impl<T> IntoProp<ReadOnlyMutable<T>> for PropMutable<Mutable<T>> {
+    #[inline]
+    fn into_prop(self) -> ReadOnlyMutable<T> { self.0.read_only() }
+}
+
+impl<T> IntoProp<ReadOnlyMutable<T>> for PropMutable<ReadOnlyMutable<T>> {
+    #[inline]
+    fn into_prop(self) -> ReadOnlyMutable<T> { self.0 }
+}
+
+impl<T> IntoProp<MaybeSignal<T>> for PropMutable<Mutable<T>> {
+    #[inline]
+    fn into_prop(self) -> MaybeSignal<T> { MaybeSignal::Mutable(self.0.read_only()) }
+}
+
+impl<T> IntoProp<MaybeSignal<T>> for PropMutable<ReadOnlyMutable<T>> {
+    #[inline]
+    fn into_prop(self) -> MaybeSignal<T> { MaybeSignal::Mutable(self.0) }
+}
+
+impl<S: Signal + 'static> IntoProp<MaybeSignal<S::Item>> for PropSignal<S> {
+    #[inline]
+    fn into_prop(self) -> MaybeSignal<S::Item> { MaybeSignal::from_signal(self.0) }
+}
+
+impl<S: Signal> IntoProp<S> for PropSignal<S> {
+    #[inline]
+    fn into_prop(self) -> S { self.0 }
+}
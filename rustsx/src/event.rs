@@ -0,0 +1,9 @@
+/// Gives closures in `onclick={|event| ..}` their event type up front, so `event` doesn't need annotating
+#[doc(hidden)]
+#[inline]
+pub fn handler<E, F: FnOnce(E)>(handler: F) -> F { handler }
+
+/// Like `handler`, for `onclick={|event, element| ..}`, with the element typed by its tag, e.g. `HtmlButtonElement`
+#[doc(hidden)]
+#[inline]
+pub fn handler_with_element<E, A, F: FnOnce(E, A)>(handler: F) -> F { handler }
@@ -1,8 +1,19 @@
-pub use {futures_signals, rsx_dominator as dominator, rsx_macros, wasm_bindgen};
+pub use {futures_signals, rsx_dominator as dominator, rsx_macros, wasm_bindgen, web_sys};
 use {rsx_dominator::Dom, web_sys::window};
 
+pub mod attr;
+pub mod bind;
+pub mod child;
+pub mod class;
+pub mod component;
+pub mod event;
+pub mod node_ref;
+pub mod reactive;
+pub mod style;
+
 pub mod prelude {
     pub use {
+        crate::{component::Children, node_ref::NodeRef, reactive::MaybeSignal},
         rsx_dominator::*,
         rsx_macros::*,
         wasm_bindgen::{self, prelude::*},
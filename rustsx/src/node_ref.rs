@@ -0,0 +1,41 @@
+use {
+    futures_signals::signal::{Mutable, MutableSignalCloned},
+    rsx_dominator::DomBuilder,
+    wasm_bindgen::JsCast,
+};
+
+/// A handle to an element made by `rsx!`, filled in with `<input ref={node_ref} />`.
+///
+/// It holds the element while it's in the document, and `None` before it's inserted and after it's removed. `T` is the
+/// tag's `web_sys` type (e.g. `HtmlInputElement` for `<input>`) or anything it derefs to, such as `HtmlElement`.
+#[derive(Clone)]
+pub struct NodeRef<T>(Mutable<Option<T>>);
+
+impl<T> NodeRef<T> {
+    pub fn new() -> Self { Self(Mutable::new(None)) }
+}
+
+impl<T: Clone> NodeRef<T> {
+    /// The element, if it's currently in the document
+    pub fn get(&self) -> Option<T> { self.0.get_cloned() }
+
+    /// Changes to `Some` when the element is inserted, and back to `None` when it's removed
+    pub fn signal(&self) -> MutableSignalCloned<Option<T>> { self.0.signal_cloned() }
+}
+
+impl<T> Default for NodeRef<T> {
+    fn default() -> Self { Self::new() }
+}
+
+/// Used by `rsx!` for `ref={node_ref}`, `A` being the tag's type
+#[doc(hidden)]
+pub fn bind<A, T>(dom: DomBuilder<A>, node_ref: &NodeRef<T>) -> DomBuilder<A>
+where
+    A: AsRef<T> + JsCast + Clone + 'static,
+    T: Clone + 'static,
+{
+    let inserted = node_ref.0.clone();
+    let removed = node_ref.0.clone();
+    dom.after_inserted(move |element| inserted.set(Some(AsRef::<T>::as_ref(&element).clone())))
+        .after_removed(move |_| removed.set(None))
+}
@@ -0,0 +1,218 @@
+use {
+    crate::component::{PropMutable, PropSignal, PropValue},
+    futures_signals::signal::{
+        Always, Broadcaster, BroadcasterSignalCloned, Mutable, MutableSignalCloned, ReadOnlyMutable, Signal,
+    },
+    pin_project::pin_project,
+    std::{
+        pin::Pin,
+        task::{Context, Poll},
+    },
+};
+
+/// A value handed to `rsx!` that is either fixed, or changes over time.
+///
+/// The macro can't see types, so it sorts expressions into one of these at compile time
+/// (see [`Probe`]) and the runtime helpers pick `.attr` / `.attr_signal` etc. from there.
+pub enum Reactive<T, S> {
+    Value(T),
+    Signal(S),
+}
+
+impl<T, S: Signal<Item = T>> Reactive<T, S> {
+    /// Turns either kind into a signal, a fixed value behaves like `always(value)`
+    #[inline]
+    pub fn into_signal(self) -> ReactiveSignal<T, S> {
+        match self {
+            Reactive::Value(value) => ReactiveSignal::Value(Some(value)),
+            Reactive::Signal(signal) => ReactiveSignal::Signal(signal),
+        }
+    }
+}
+
+/// Signal returned by [`Reactive::into_signal`]
+#[pin_project(project = ReactiveSignalProj)]
+#[must_use = "Signals do nothing unless polled"]
+pub enum ReactiveSignal<T, S> {
+    Value(Option<T>),
+    Signal(#[pin] S),
+}
+
+impl<T, S: Signal<Item = T>> Signal for ReactiveSignal<T, S> {
+    type Item = T;
+
+    #[inline]
+    fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<T>> {
+        match self.project() {
+            ReactiveSignalProj::Value(value) => Poll::Ready(value.take()),
+            ReactiveSignalProj::Signal(signal) => signal.poll_change(cx),
+        }
+    }
+}
+
+/// Wraps a reference to an expression so method resolution can pick its [`Reactive`] kind.
+///
+/// Signals are checked first, then `Mutable` / `ReadOnlyMutable`, and anything else is a plain value:
+///
+/// ```rust,ignore
+/// use rustsx::reactive::{ViaMutable, ViaSignal, ViaValue};
+/// let value = some_expr;
+/// let reactive = (&&&Probe(&value)).__rsx_kind().reactive(value);
+/// ```
+#[doc(hidden)]
+pub struct Probe<'a, T>(pub &'a T);
+
+#[doc(hidden)]
+pub struct SignalKind;
+#[doc(hidden)]
+pub struct MutableKind;
+#[doc(hidden)]
+pub struct ValueKind;
+
+#[doc(hidden)]
+pub trait ViaSignal {
+    #[inline]
+    fn __rsx_kind(&self) -> SignalKind { SignalKind }
+}
+
+impl<T: Signal> ViaSignal for &&Probe<'_, T> {}
+
+#[doc(hidden)]
+pub trait ViaMutable {
+    #[inline]
+    fn __rsx_kind(&self) -> MutableKind { MutableKind }
+}
+
+impl<T> ViaMutable for &Probe<'_, Mutable<T>> {}
+impl<T> ViaMutable for &Probe<'_, ReadOnlyMutable<T>> {}
+
+#[doc(hidden)]
+pub trait ViaValue {
+    #[inline]
+    fn __rsx_kind(&self) -> ValueKind { ValueKind }
+}
+
+impl<T> ViaValue for Probe<'_, T> {}
+
+impl SignalKind {
+    #[inline]
+    pub fn reactive<S: Signal>(self, signal: S) -> Reactive<S::Item, S> { Reactive::Signal(signal) }
+
+    #[inline]
+    pub fn prop<S>(self, signal: S) -> PropSignal<S> { PropSignal(signal) }
+}
+
+impl MutableKind {
+    #[inline]
+    pub fn reactive<M: MutableLike>(self, mutable: M) -> Reactive<M::Item, MutableSignalCloned<M::Item>> {
+        Reactive::Signal(mutable.to_signal_cloned())
+    }
+
+    #[inline]
+    pub fn prop<M>(self, mutable: M) -> PropMutable<M> { PropMutable(mutable) }
+}
+
+impl ValueKind {
+    #[inline]
+    pub fn reactive<T>(self, value: T) -> Reactive<T, Always<T>> { Reactive::Value(value) }
+
+    #[inline]
+    pub fn prop<T>(self, value: T) -> PropValue<T> { PropValue(value) }
+}
+
+/// `Mutable` and `ReadOnlyMutable`, both of which can hand out a cloned signal
+#[doc(hidden)]
+pub trait MutableLike {
+    type Item: Clone;
+
+    fn to_signal_cloned(&self) -> MutableSignalCloned<Self::Item>;
+}
+
+impl<T: Clone> MutableLike for Mutable<T> {
+    type Item = T;
+
+    #[inline]
+    fn to_signal_cloned(&self) -> MutableSignalCloned<T> { self.signal_cloned() }
+}
+
+impl<T: Clone> MutableLike for ReadOnlyMutable<T> {
+    type Item = T;
+
+    #[inline]
+    fn to_signal_cloned(&self) -> MutableSignalCloned<T> { self.signal_cloned() }
+}
+
+/// A value that may or may not change over time, for component props.
+///
+/// A `MaybeSignal<T>` prop can be given a plain value, a `Mutable<T>`, a `ReadOnlyMutable<T>` or any
+/// `Signal<Item = T>`, and `signal_cloned` can be called on it as many times as needed:
+///
+/// ```rust,ignore
+/// #[component]
+/// fn greeting(name: MaybeSignal<String>) -> Dom {
+///     rsx! { <p>Hello {name}</p> }
+/// }
+///
+/// rsx! { <Greeting name="World" /> }
+/// rsx! { <Greeting name={name_mutable.clone()} /> }
+/// rsx! { <Greeting name={user.signal_ref(|user| user.name.clone())} /> }
+/// ```
+#[derive(Clone)]
+pub enum MaybeSignal<T> {
+    Value(T),
+    Mutable(ReadOnlyMutable<T>),
+    Signal(Broadcaster<Pin<Box<dyn Signal<Item = T>>>>),
+}
+
+impl<T> MaybeSignal<T> {
+    /// Wraps any signal, so it can be shared
+    pub fn from_signal<S: Signal<Item = T> + 'static>(signal: S) -> Self { MaybeSignal::Signal(Broadcaster::new(Box::pin(signal))) }
+}
+
+impl<T: Clone> MaybeSignal<T> {
+    /// A signal of the current value, which only ever has one value for `MaybeSignal::Value`
+    pub fn signal_cloned(&self) -> MaybeSignalCloned<T> {
+        match self {
+            MaybeSignal::Value(value) => MaybeSignalCloned::Value(Some(value.clone())),
+            MaybeSignal::Mutable(mutable) => MaybeSignalCloned::Mutable(mutable.signal_cloned()),
+            MaybeSignal::Signal(broadcaster) => MaybeSignalCloned::Signal(broadcaster.signal_cloned()),
+        }
+    }
+}
+
+impl<T> From<T> for MaybeSignal<T> {
+    #[inline]
+    fn from(value: T) -> Self { MaybeSignal::Value(value) }
+}
+
+impl<T> From<Mutable<T>> for MaybeSignal<T> {
+    #[inline]
+    fn from(mutable: Mutable<T>) -> Self { MaybeSignal::Mutable(mutable.read_only()) }
+}
+
+impl<T> From<ReadOnlyMutable<T>> for MaybeSignal<T> {
+    #[inline]
+    fn from(mutable: ReadOnlyMutable<T>) -> Self { MaybeSignal::Mutable(mutable) }
+}
+
+/// Signal returned by [`MaybeSignal::signal_cloned`]
+#[pin_project(project = MaybeSignalClonedProj)]
+#[must_use = "Signals do nothing unless polled"]
+pub enum MaybeSignalCloned<T> {
+    Value(Option<T>),
+    Mutable(#[pin] MutableSignalCloned<T>),
+    Signal(#[pin] BroadcasterSignalCloned<Pin<Box<dyn Signal<Item = T>>>>),
+}
+
+impl<T: Clone> Signal for MaybeSignalCloned<T> {
+    type Item = T;
+
+    #[inline]
+    fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<T>> {
+        match self.project() {
+            MaybeSignalClonedProj::Value(value) => Poll::Ready(value.take()),
+            MaybeSignalClonedProj::Mutable(signal) => signal.poll_change(cx),
+            MaybeSignalClonedProj::Signal(signal) => signal.poll_change(cx),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use {
+    crate::reactive::{MutableKind, MutableLike, SignalKind, ValueKind},
+    futures_signals::signal::Signal,
+    rsx_dominator::{
+        DomBuilder,
+        traits::{MultiStr, OptionStr},
+    },
+    web_sys::HtmlElement,
+};
+
+// Sets one property of a `style={{ name: value }}` object for each kind of value sorted by `rsx!`. Values can be a
+// `&str`, a `String` or an `Option` of either, and `None` leaves the property off, or removes it again from a signal.
+// Like `DomBuilder::style`, these panic if the browser doesn't support the property.
+
+impl ValueKind {
+    #[inline]
+    pub fn style<A, B, T>(self, dom: DomBuilder<A>, name: &'static str, important: bool, value: T) -> DomBuilder<A>
+    where
+        A: AsRef<HtmlElement>,
+        B: MultiStr,
+        T: OptionStr<Output = B>,
+    {
+        match value.into_option() {
+            Some(value) if important => dom.style_important(name, value),
+            Some(value) => dom.style(name, value),
+            None => dom,
+        }
+    }
+}
+
+impl MutableKind {
+    #[inline]
+    pub fn style<A, B, M>(self, dom: DomBuilder<A>, name: &'static str, important: bool, mutable: M) -> DomBuilder<A>
+    where
+        A: AsRef<HtmlElement>,
+        B: MultiStr,
+        M: MutableLike,
+        M::Item: OptionStr<Output = B> + 'static,
+    {
+        SignalKind.style(dom, name, important, mutable.to_signal_cloned())
+    }
+}
+
+impl SignalKind {
+    #[inline]
+    pub fn style<A, B, S>(self, dom: DomBuilder<A>, name: &'static str, important: bool, signal: S) -> DomBuilder<A>
+    where
+        A: AsRef<HtmlElement>,
+        B: MultiStr,
+        S: Signal + 'static,
+        S::Item: OptionStr<Output = B>,
+    {
+        if important {
+            dom.style_important_signal(name, signal)
+        } else {
+            dom.style_signal(name, signal)
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//! Attributes in a browser, run with `wasm-pack test --headless --firefox rustsx`
+#![cfg(target_arch = "wasm32")]
+use {
+    rustsx::{dominator, futures_signals::signal::Mutable, prelude::*},
+    wasm_bindgen::JsValue,
+    wasm_bindgen_futures::JsFuture,
+    wasm_bindgen_test::*,
+    web_sys::Element,
+};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+// Lets the futures that follow signals run
+async fn tick() { JsFuture::from(js_sys::Promise::resolve(&JsValue::NULL)).await.unwrap(); }
+
+#[wasm_bindgen_test]
+async fn none_removes_href() {
+    let href = Mutable::new(Some(String::from("/home")));
+    let link = NodeRef::<Element>::new();
+    dominator::append_dom(&dominator::body(), rsx! { <a ref={link} href={href.clone()}>"Home"</a> });
+    tick().await;
+
+    let link = link.get().unwrap();
+    assert_eq!(link.get_attribute("href").as_deref(), Some("/home"));
+
+    href.set(None);
+    tick().await;
+    assert_eq!(link.get_attribute("href"), None);
+
+    href.set(Some(String::from("/away")));
+    tick().await;
+    assert_eq!(link.get_attribute("href").as_deref(), Some("/away"));
+}
+
+#[wasm_bindgen_test]
+async fn none_leaves_href_off() {
+    let href: Option<&str> = None;
+    let link = NodeRef::<Element>::new();
+    dominator::append_dom(&dominator::body(), rsx! { <a ref={link} href={href} title="Nowhere">"Nowhere"</a> });
+    tick().await;
+
+    let link = link.get().unwrap();
+    assert_eq!(link.get_attribute("href"), None);
+    assert_eq!(link.get_attribute("title").as_deref(), Some("Nowhere"));
+}
+
+#[wasm_bindgen_test]
+async fn boolean_attributes_toggle() {
+    let div = NodeRef::<Element>::new();
+    let input = NodeRef::<Element>::new();
+    dominator::append_dom(&dominator::body(), rsx! {
+        <div ref={div} hidden={false} inert>
+            <input ref={input} disabled={true} readonly="readonly" required={false} />
+        </div>
+    });
+    tick().await;
+
+    let (div, input) = (div.get().unwrap(), input.get().unwrap());
+    assert_eq!(div.get_attribute("hidden"), None);
+    assert_eq!(div.get_attribute("inert").as_deref(), Some(""));
+    assert_eq!(input.get_attribute("disabled").as_deref(), Some(""));
+    assert_eq!(input.get_attribute("readonly").as_deref(), Some("readonly"));
+    assert_eq!(input.get_attribute("required"), None);
+}
+
+#[wasm_bindgen_test]
+async fn enumerated_attributes_take_the_bool_as_text() {
+    let editing = Mutable::new(false);
+    let div = NodeRef::<Element>::new();
+    dominator::append_dom(&dominator::body(), rsx! {
+        <div ref={div} draggable={true} spellcheck={false} contenteditable={editing.clone()} aria-hidden={false} />
+    });
+    tick().await;
+
+    let div = div.get().unwrap();
+    assert_eq!(div.get_attribute("draggable").as_deref(), Some("true"));
+    assert_eq!(div.get_attribute("spellcheck").as_deref(), Some("false"));
+    assert_eq!(div.get_attribute("contenteditable").as_deref(), Some("false"));
+    assert_eq!(div.get_attribute("aria-hidden").as_deref(), Some("false"));
+
+    editing.set(true);
+    tick().await;
+    assert_eq!(div.get_attribute("contenteditable").as_deref(), Some("true"));
+}
+
+#[wasm_bindgen_test]
+async fn strings_on_boolean_attributes_are_set_as_written() {
+    let div = NodeRef::<Element>::new();
+    dominator::append_dom(&dominator::body(), rsx! { <div ref={div} hidden="until-found">"Found"</div> });
+    tick().await;
+
+    assert_eq!(div.get().unwrap().get_attribute("hidden").as_deref(), Some("until-found"));
+}
@@ -0,0 +1,56 @@
+//! Event listeners in a browser, run with `wasm-pack test --headless --firefox rustsx`
+#![cfg(target_arch = "wasm32")]
+use {
+    rustsx::{dominator, prelude::*},
+    std::{cell::RefCell, rc::Rc},
+    wasm_bindgen::JsValue,
+    wasm_bindgen_futures::JsFuture,
+    wasm_bindgen_test::*,
+    web_sys::HtmlElement,
+};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+// Lets the element be mounted and its ref filled
+async fn tick() { JsFuture::from(js_sys::Promise::resolve(&JsValue::NULL)).await.unwrap(); }
+
+// Listeners run in the capture phase like plain ones when given modifiers, so parents still run before children
+#[wasm_bindgen_test]
+async fn modifiers_keep_the_phase() {
+    let order = Rc::new(RefCell::new(Vec::new()));
+    let button = NodeRef::<HtmlElement>::new();
+    dominator::append_dom(&dominator::body(), rsx! {
+        <div onclick={|_| order.borrow_mut().push("div")}>
+            <p onclick:once={|_| order.borrow_mut().push("p")}>
+                <button ref={button} onclick:prevent={|_| order.borrow_mut().push("button")} />
+            </p>
+        </div>
+    });
+    tick().await;
+
+    let button = button.get().unwrap();
+    button.click();
+    assert_eq!(*order.borrow(), ["div", "p", "button"]);
+
+    // `:once` removed the listener on `<p>`
+    button.click();
+    assert_eq!(*order.borrow(), ["div", "p", "button", "div", "button"]);
+}
+
+// `:capture` on a custom event, which otherwise listens in the bubble phase, sees an event that doesn't bubble
+#[wasm_bindgen_test]
+async fn capture_on_custom_events() {
+    let order = Rc::new(RefCell::new(Vec::new()));
+    let button = NodeRef::<HtmlElement>::new();
+    dominator::append_dom(&dominator::body(), rsx! {
+        <div on:ping:capture={|_| order.borrow_mut().push("div")} on:pong={|_| order.borrow_mut().push("pong")}>
+            <button ref={button} on:ping:once={|_| order.borrow_mut().push("button")} />
+        </div>
+    });
+    tick().await;
+
+    let button = button.get().unwrap();
+    button.dispatch_event(&web_sys::Event::new("ping").unwrap()).unwrap();
+    button.dispatch_event(&web_sys::Event::new("pong").unwrap()).unwrap();
+    assert_eq!(*order.borrow(), ["div", "button"]);
+}
@@ -0,0 +1,8 @@
+// Compile tests for `rsx!` and `#[component]`. Run `TRYBUILD=overwrite cargo test -p rustsx --test ui` to update the
+// expected errors in `tests/ui/fail/*.stderr` after changing a diagnostic.
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/pass/*.rs");
+    t.compile_fail("tests/ui/fail/*.rs");
+}
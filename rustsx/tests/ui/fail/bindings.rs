@@ -0,0 +1,19 @@
+use rustsx::{dominator::Dom, futures_signals::signal::Mutable, prelude::*};
+
+struct Color(u8, u8, u8);
+
+fn form() -> Vec<Dom> {
+    let name = Mutable::new(String::new());
+    let agree = Mutable::new(false);
+    let color = Mutable::new(Color(0, 0, 0));
+    vec![
+        rsx! { <div bind:value={name} /> },
+        rsx! { <textarea bind:checked={agree}></textarea> },
+        rsx! { <input bind:text={name} /> },
+        rsx! { <input type="checkbox" bind:checked={name} /> },
+        rsx! { <input bind:value={color} /> },
+        rsx! { <input bind:value={String::new()} /> },
+    ]
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use rustsx::{dominator::Dom, futures_signals::signal::Mutable, prelude::*};
+
+fn values(name: String, count: Mutable<u8>, maybe: Option<bool>) -> Vec<Dom> {
+    vec![
+        rsx! { <input disabled={name} /> },
+        rsx! { <input required={count.clone()} /> },
+        rsx! { <input checked={maybe} /> },
+    ]
+}
+
+fn main() {}
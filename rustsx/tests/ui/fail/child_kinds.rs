@@ -0,0 +1,25 @@
+use rustsx::{
+    dominator::Dom,
+    futures_signals::signal::Mutable,
+    prelude::*,
+};
+
+struct Point(i32, i32);
+
+fn children() -> Dom {
+    let count = Mutable::new(0);
+    rsx! {
+        <div>
+            {Point(1, 2)}
+            {Some("text")}
+            {count.signal().map(|count| vec![count])}
+        </div>
+    }
+}
+
+fn roots() -> Vec<Dom> {
+    let count = Mutable::new(0);
+    rsx! { {count.signal().map(|count| rsx! { <p>{count}</p> })} }
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use rustsx::{dominator::Dom, prelude::*};
+
+fn classes() -> Vec<Dom> {
+    vec![
+        rsx! { <p class={["card", 1]} /> },
+        rsx! { <p class:active="yes" /> },
+        rsx! { <p class={vec!["a", "b"]} /> },
+    ]
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use rustsx::{dominator::Dom, prelude::*};
+
+#[component]
+fn button(label: String) -> Dom {
+    rsx! { <button>{label}</button> }
+}
+
+fn spreads(a: ButtonProps, b: ButtonProps) -> Dom {
+    rsx! { <Button {..a} {..b} /> }
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use rustsx::prelude::*;
+
+fn main() {
+    let value = Some(1);
+    let _ = rsx! { <div>{if let Some(value) = value { <p>{value}</p> }}</div> };
+    let _ = rsx! { {if true { <p /> }} };
+}
@@ -0,0 +1,11 @@
+use rustsx::{dominator::Dom, prelude::*};
+
+fn elements() -> Vec<Dom> {
+    vec![
+        rsx! { <button onclick={|_, input: web_sys::HtmlInputElement| input.set_value("")} /> },
+        rsx! { <div onclick={|_, div| div.set_value("")} /> },
+        rsx! { <p onclick={|_, _, _| ()} /> },
+    ]
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use rustsx::{dominator::Dom, prelude::*};
+
+fn modifiers() -> Vec<Dom> {
+    vec![
+        rsx! { <div onwheel:passive:prevent={|_| ()} /> },
+        rsx! { <div ontouchstart:prevent:passive={|_| ()} /> },
+        rsx! { <div onclick:stopp={|_| ()} /> },
+    ]
+}
+
+fn main() {}
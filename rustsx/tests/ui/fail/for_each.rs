@@ -0,0 +1,9 @@
+use rustsx::{futures_signals::signal_vec::MutableVec, prelude::*};
+
+fn main() {
+    let items = MutableVec::<u32>::new();
+    let _ = rsx! { <For each={items.signal_vec()}>{|item: u32| rsx!(<li>{item}</li>)}</For> };
+    let _ = rsx! { <ul><For>{|item: u32| rsx!(<li>{item}</li>)}</For></ul> };
+    let _ = rsx! { <ul><For each={items.signal_vec()} key={1}>{|item: u32| rsx!(<li>{item}</li>)}</For></ul> };
+    let _ = rsx! { <ul><For each={items.signal_vec()}><li /></For></ul> };
+}
@@ -0,0 +1,14 @@
+use std::fmt::Display;
+
+use rustsx::{dominator::Dom, prelude::*};
+
+#[component]
+fn list<T: Display + 'static>(items: Vec<T>) -> Dom {
+    rsx! { <ul>{items.iter().map(|item| rsx! { <li>{item.to_string()}</li> })}</ul> }
+}
+
+fn lists() -> Dom {
+    rsx! { <List items={vec![vec![1]]} /> }
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+use rustsx::prelude::*;
+
+fn main() {
+    let _ = rsx! { <div><span></div> };
+}
@@ -0,0 +1,10 @@
+use rustsx::{dominator::Dom, prelude::*};
+
+#[component]
+fn heading(title: String, #[prop(default = 1)] level: u8) -> Dom {
+    rsx! { <h1 data-level={level}>{title}</h1> }
+}
+
+fn main() {
+    let _ = rsx! { <Heading level={2} /> };
+}
@@ -0,0 +1,12 @@
+use rustsx::{dominator::Dom, prelude::*};
+
+fn refs() -> Vec<Dom> {
+    let input = NodeRef::<web_sys::HtmlInputElement>::new();
+    let count = 0;
+    vec![
+        rsx! { <textarea ref={input}></textarea> },
+        rsx! { <div ref={count} /> },
+    ]
+}
+
+fn main() {}
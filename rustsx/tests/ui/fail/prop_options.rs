@@ -0,0 +1,8 @@
+use rustsx::prelude::*;
+
+#[component]
+fn heading(#[prop(fallback = 1)] level: u8) -> rustsx::dominator::Dom {
+    rsx! { <h1 data-level={level} /> }
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use rustsx::{
+    dominator::Dom,
+    futures_signals::signal::{Mutable, ReadOnlyMutable, SignalExt},
+    prelude::*,
+};
+
+#[component]
+fn counter(label: String, count: Mutable<u32>, title: MaybeSignal<String>) -> Dom {
+    rsx! { <p title={title.signal_cloned()}>{label}{count.signal()}</p> }
+}
+
+fn counters() -> Dom {
+    let count = Mutable::new(1);
+    let read_only: ReadOnlyMutable<u32> = count.read_only();
+    rsx! {
+        <div>
+            <Counter label={count.signal().map(|count| count.to_string())} count={1} title="" />
+            <Counter label="" count={read_only} title="" />
+            <Counter label="" count={1} title={count.signal()} />
+        </div>
+    }
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use rustsx::prelude::*;
+
+fn main() {
+    let _ = rsx! { <p>"a" > "b"</p> };
+    let _ = rsx! { <div></div></span> };
+    let _ = rsx! { <input value= /> };
+}
@@ -0,0 +1,17 @@
+use rustsx::{dominator::Dom, prelude::*};
+
+#[component]
+fn card(title: String) -> Dom {
+    rsx! { <div>{title}</div> }
+}
+
+fn styled() -> Vec<Dom> {
+    vec![
+        rsx! { <p style={{ color: , display: "none" }} /> },
+        rsx! { <p style={{ width: 12 }} /> },
+        rsx! { <svg style={{ color: "red" }} /> },
+        rsx! { <Card title="Card" style={{ color: "red" }} /> },
+    ]
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+use rustsx::{dominator::Dom, prelude::*};
+
+fn unknown() -> Dom {
+    rsx! {
+        <svg>
+            <cirle r="4" />
+            <lineargradient />
+            <div />
+            <foreignObject><dvi /></foreignObject>
+        </svg>
+    }
+}
+
+fn styles() -> Dom {
+    rsx! {
+        <svg style={{ color: "red" }} />
+    }
+}
+
+fn elements() -> Dom {
+    rsx! {
+        <svg><rect onclick={|_, rect: web_sys::HtmlElement| rect.click()} /></svg>
+    }
+}
+
+fn main() {}
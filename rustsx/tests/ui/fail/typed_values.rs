@@ -0,0 +1,22 @@
+use rustsx::{dominator::Dom, futures_signals::signal::Mutable, prelude::*};
+
+fn literals() -> Dom {
+    rsx! {
+        <form method="put" target="_new">
+            <input type="txt" maxlength="ten" />
+            <td colspan={true} />
+            <textarea wrap="none"></textarea>
+        </form>
+    }
+}
+
+fn expressions(text: String, port: u16, url: Mutable<u32>) -> Vec<Dom> {
+    vec![
+        rsx! { <td colspan={text} /> },
+        rsx! { <a href={port} /> },
+        rsx! { <img src={url.clone()} /> },
+        rsx! { <meter value={Some("half")} /> },
+    ]
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use rustsx::prelude::*;
+
+fn main() {
+    let _ = rsx! { <div><p>"text"</p> };
+    let _ = rsx! { <div class="a" };
+}
@@ -0,0 +1,10 @@
+use rustsx::{dominator::Dom, prelude::*};
+
+#[component]
+fn badge(label: String) -> Dom {
+    rsx! { <span>{label}</span> }
+}
+
+fn main() {
+    let _ = rsx! { <Badge label="New"><p>"Nested"</p></Badge> };
+}
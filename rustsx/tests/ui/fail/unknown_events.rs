@@ -0,0 +1,7 @@
+use rustsx::{dominator::Dom, prelude::*};
+
+fn events() -> Dom {
+    rsx! { <button onclik={|_| ()} /> }
+}
+
+fn main() {}
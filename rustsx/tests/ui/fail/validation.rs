@@ -0,0 +1,12 @@
+use rustsx::{dominator::Dom, prelude::*};
+
+fn page() -> Dom {
+    rsx! {
+        <div>
+            <dvi />
+            <input>"x"</input>
+        </div>
+    }
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![deny(deprecated)]
+
+use rustsx::{dominator::Dom, prelude::*};
+
+fn page() -> Dom {
+    rsx! {
+        <center>
+            <p clas="x" align="left" />
+        </center>
+    }
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+#![allow(dead_code)]
+#![deny(deprecated)]
+
+use rustsx::{dominator::Dom, prelude::*};
+
+#[allow(deprecated)]
+fn legacy() -> Dom {
+    rsx! {
+        <center>
+            <font face="serif" clas="x">"Old"</font>
+        </center>
+    }
+}
+
+mod legacy_module {
+    #![allow(deprecated)]
+
+    use rustsx::{dominator::Dom, prelude::*};
+
+    fn table() -> Dom {
+        rsx! { <td bgcolor="red" /> }
+    }
+}
+
+fn main() {}
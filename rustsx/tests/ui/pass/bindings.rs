@@ -0,0 +1,51 @@
+#![allow(dead_code)]
+use rustsx::{bind::BindValue, dominator::Dom, futures_signals::signal::Mutable, prelude::*};
+
+#[derive(Clone, PartialEq)]
+enum Plan {
+    Free,
+    Pro,
+}
+
+impl BindValue for Plan {
+    fn to_input(&self) -> String {
+        match self {
+            Plan::Free => "free".to_owned(),
+            Plan::Pro => "pro".to_owned(),
+        }
+    }
+
+    fn from_input(input: &str) -> Option<Self> {
+        match input {
+            "free" => Some(Plan::Free),
+            "pro" => Some(Plan::Pro),
+            _ => None,
+        }
+    }
+}
+
+fn form() -> Dom {
+    let name = Mutable::new(String::new());
+    let age = Mutable::new(30u8);
+    let bio = Mutable::new(String::new());
+    let agree = Mutable::new(false);
+    let plan = Mutable::new(Plan::Free);
+    let size = Mutable::new(1.5f64);
+    rsx! {
+        <form>
+            <input bind:value={name} />
+            <input type="number" bind:value={age} />
+            <textarea bind:value={bio}></textarea>
+            <select bind:value={size}>
+                <option value="1.5">"Small"</option>
+                <option value="3">"Large"</option>
+            </select>
+            <input type="checkbox" bind:checked={agree} />
+            <p>{agree.signal()}</p>
+            <input type="radio" value="free" bind:group={plan} />
+            <input type="radio" value="pro" bind:group={plan} />
+        </form>
+    }
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+#![allow(dead_code)]
+use rustsx::{dominator::Dom, futures_signals::signal::Mutable, prelude::*};
+
+fn form(hidden: Mutable<bool>, disabled: Mutable<bool>) -> Dom {
+    rsx! {
+        <form hidden={hidden}>
+            <input disabled required />
+            <input type="checkbox" checked={true} />
+            <button disabled={disabled.signal()}>"Go"</button>
+        </form>
+    }
+}
+
+// Strings on boolean attributes are set as written
+fn strings() -> Dom {
+    rsx! {
+        <select>
+            <input disabled="disabled" readonly="" />
+            <option selected="selected">"One"</option>
+            <div hidden="until-found">"Found"</div>
+        </select>
+    }
+}
+
+// Enumerated and `aria-*` attributes take the text of the bool
+fn enumerated(editing: Mutable<bool>) -> Dom {
+    rsx! {
+        <div draggable={true} spellcheck={false} contenteditable={editing.clone()} aria-hidden={false} data-open={true} />
+    }
+}
+
+fn main() {}
@@ -0,0 +1,58 @@
+#![allow(dead_code)]
+use rustsx::{
+    dominator::Dom,
+    futures_signals::{
+        signal::{Mutable, SignalExt},
+        signal_vec::MutableVec,
+    },
+    prelude::*,
+};
+
+#[component]
+fn badge(label: String) -> Dom {
+    rsx! { <span>{label}</span> }
+}
+
+fn children() -> Dom {
+    let name = "Ada";
+    let owned = String::from("Lovelace");
+    let count = Mutable::new(0u32);
+    let shown = Mutable::new(true);
+    let items = MutableVec::new_with_values(vec![1, 2, 3]);
+    let doms = vec![rsx! { <li>"One"</li> }, rsx! { <li>"Two"</li> }];
+    let maybe: Option<Dom> = None;
+    let signal = count.signal().map(|count| count * 2);
+    rsx! {
+        <div>
+            {name}
+            {owned}
+            {42}
+            {1.5}
+            {'x'}
+            {Dom::text("Dom")}
+            {Badge(BadgeProps::builder().label("Called".to_string()).build())}
+            <ul>{doms}</ul>
+            {maybe}
+            {(0..3).map(|index| rsx! { <i>{index}</i> })}
+            {count.clone()}
+            {count.read_only()}
+            {signal}
+            {count.signal_ref(|count| format!("{count} clicks"))}
+            {shown.signal().map(|shown| rsx! { <b>{shown.to_string()}</b> })}
+            {shown.signal().map(|shown| shown.then(|| rsx! { <hr /> }))}
+            <ul>{items.signal_vec().map(|item| rsx! { <li>{item}</li> })}</ul>
+        </div>
+    }
+}
+
+fn roots() -> Dom {
+    let label = String::from("Root");
+    let doms = rsx! {
+        {label}
+        {"text"}
+        {vec![rsx! { <p /> }]}
+    };
+    rsx! { <div>{doms}</div> }
+}
+
+fn main() {}
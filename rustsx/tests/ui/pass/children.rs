@@ -0,0 +1,27 @@
+#![allow(dead_code)]
+use rustsx::{dominator::Dom, futures_signals::signal::Mutable, prelude::*};
+
+#[component]
+fn card(title: Mutable<String>, children: Children) -> Dom {
+    rsx! {
+        <div class="card">
+            <h2>{title}</h2>
+            {children}
+            <footer>{children}</footer>
+        </div>
+    }
+}
+
+fn page(title: Mutable<String>, count: Mutable<u32>) -> Dom {
+    rsx! {
+        <div>
+            <Card title={title.clone()}>
+                <p>Nested markup is passed as children</p>
+                <button onclick={|_| count.set(count.get() + 1)}>{count.clone()}</button>
+            </Card>
+            <Card title={title} />
+        </div>
+    }
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+#![allow(dead_code)]
+use rustsx::{
+    dominator::Dom,
+    futures_signals::signal::{Mutable, SignalExt},
+    prelude::*,
+};
+
+fn classes(active: Mutable<bool>, theme: Mutable<String>, size: Mutable<Option<String>>) -> Dom {
+    let extra: Option<&str> = Some("extra");
+    rsx! {
+        <div>
+            <p class="plain two">"Plain"</p>
+            <p class={theme.clone()} class:active={active.clone()}>"Mutable"</p>
+            <p class={["card", "card-body"]} class:selected={true} class:hidden={false}>"List"</p>
+            <p class={["card", extra, theme.read_only(), size]} class:active={active.signal()}>"Mixed list"</p>
+            <p class={active.signal().map(|active| if active { "on" } else { "off" })}>"Signal"</p>
+            <p class={format!("level-{}", 2)} class:data-state-open={active.get()}>"Owned"</p>
+        </div>
+    }
+}
+
+fn main() {}
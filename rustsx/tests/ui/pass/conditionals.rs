@@ -0,0 +1,47 @@
+#![allow(dead_code)]
+use rustsx::{
+    dominator::Dom,
+    futures_signals::signal::{Mutable, SignalExt},
+    prelude::*,
+};
+
+enum Status {
+    Loading,
+    Failed(String),
+    Done,
+}
+
+fn plain(logged_in: bool) -> Dom {
+    rsx! {
+        <nav>
+            {if logged_in { <a href="/logout">"Log out"</a> } else { <a href="/login">"Log in"</a> }}
+            {if logged_in { <span>"Welcome"</span> }}
+        </nav>
+    }
+}
+
+fn signals(logged_in: Mutable<bool>, admin: Mutable<bool>, status: Mutable<u8>) -> Dom {
+    rsx! {
+        <nav>
+            {if logged_in.clone() { <a href="/logout">"Log out"</a> } else if admin.signal() { <>"a" "b"</> }}
+            {match status.signal().map(|status| status > 1) {
+                true => <p>"Ready"</p>,
+                false => { <p>"Loading"</p> <p>"..."</p> }
+            }}
+        </nav>
+    }
+}
+
+fn matches(status: Status) -> Dom {
+    rsx! {
+        <div>
+            {match status {
+                Status::Loading => <p>"Loading"</p>,
+                Status::Failed(error) if !error.is_empty() => <p>{error}</p>,
+                _ => <p>"Done"</p>,
+            }}
+        </div>
+    }
+}
+
+fn main() {}
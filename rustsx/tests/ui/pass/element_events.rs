@@ -0,0 +1,20 @@
+#![allow(dead_code)]
+use rustsx::{dominator::Dom, futures_signals::signal::Mutable, prelude::*};
+
+fn elements(text: Mutable<String>) -> Dom {
+    rsx! {
+        <form onsubmit={|_, form| form.reset()}>
+            <input oninput={|_, input| text.set(input.value())} onkeydown:prevent={|event, input| {
+                if event.key() == "Escape" { input.set_value(""); }
+            }} />
+            <textarea onchange={|_, area: web_sys::HtmlTextAreaElement| text.set(area.value())}></textarea>
+            <button onclick:stop:once={|_, button| button.set_disabled(true)}>"Once"</button>
+            <br onclick={|_, br: web_sys::HtmlBrElement| br.set_clear("all")} />
+            <section onclick={|_, section: web_sys::HtmlElement| section.set_hidden(true)} />
+            <my-element on:my-event={|_, el: web_sys::HtmlElement| el.blur().unwrap()} />
+            <canvas onclick={|_, canvas| { let _: u32 = canvas.width(); }} />
+        </form>
+    }
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#![allow(dead_code)]
+use rustsx::{dominator::Dom, prelude::*};
+
+fn modifiers() -> Dom {
+    rsx! {
+        <form onsubmit:prevent={|_| ()}>
+            <a href="/" onclick:prevent:stop={|_| ()}>"Link"</a>
+            <div onclick:self={|_| ()} onscroll:passive={|_| ()} onfocus:capture:once={|_| ()}>
+                <button onclick:stop={|_, button: web_sys::HtmlButtonElement| button.set_disabled(true)}>"Once"</button>
+            </div>
+            <div on:my-event:stop:once={|event| { let _: String = event.event_type(); }} />
+        </form>
+    }
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+#![allow(dead_code)]
+use rustsx::{dominator::Dom, prelude::*};
+
+fn events() -> Dom {
+    rsx! {
+        <form onreset={|_| ()} oninvalid={|_| ()} onsubmit={|_| ()}>
+            <input onkeypress={|event| { let _: String = event.key(); }} onkeydown={|_| ()} />
+            <div
+                ontransitionend={|event| { let _: String = event.property_name(); }}
+                onanimationend={|_| ()}
+                onmouseenter={|event| { let _: i32 = event.mouse_x(); }}
+                onmouseleave={|_| ()}
+                on:my-event={|event| { let _: String = event.event_type(); }}
+            />
+        </form>
+    }
+}
+
+fn main() {}
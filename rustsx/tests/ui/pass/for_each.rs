@@ -0,0 +1,29 @@
+#![allow(dead_code)]
+use rustsx::{
+    dominator::Dom,
+    futures_signals::{signal::Mutable, signal_vec::MutableVec},
+    prelude::*,
+};
+
+fn list(items: MutableVec<String>, selected: Mutable<Option<String>>) -> Dom {
+    rsx! {
+        <ul>
+            <li>"Header"</li>
+            <For each={items.signal_vec_cloned()}>
+                {|item: String| rsx!(<li onclick={|_| selected.set(Some(item.clone()))}>{item.clone()}</li>)}
+            </For>
+        </ul>
+    }
+}
+
+fn animated(items: MutableVec<u32>) -> Dom {
+    rsx! {
+        <ul>
+            <For each={items.signal_vec()} animate={300}>
+                {|item: u32, _animation| rsx!(<li>{item}</li>)}
+            </For>
+        </ul>
+    }
+}
+
+fn main() {}
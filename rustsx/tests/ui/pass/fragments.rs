@@ -0,0 +1,32 @@
+#![allow(dead_code)]
+use rustsx::{dominator::Dom, prelude::*};
+
+fn fragment() -> Vec<Dom> {
+    rsx! {
+        <>
+            <li>"One"</li>
+            <li>"Two"</li>
+        </>
+    }
+}
+
+fn roots() -> Vec<Dom> {
+    rsx! {
+        <li>"One"</li>
+        <li>"Two"</li>
+    }
+}
+
+fn nested() -> Dom {
+    rsx! {
+        <ul>
+            <>
+                <li>"One"</li>
+                <li>"Two"</li>
+            </>
+            <li>"Three"</li>
+        </ul>
+    }
+}
+
+fn main() {}
@@ -0,0 +1,53 @@
+#![allow(dead_code)]
+#![deny(missing_docs)]
+//! Generic components keep their bounds, attributes and docs.
+use std::fmt::Display;
+
+use rustsx::{dominator::Dom, prelude::*};
+
+/// A list of anything that can be shown.
+#[component]
+pub fn list<T: Display + 'static>(
+    /// The items to show
+    items: Vec<T>,
+) -> Dom {
+    rsx! {
+        <ul>
+            {items.iter().map(|item| rsx! { <li>{item.to_string()}</li> })}
+        </ul>
+    }
+}
+
+/// A label borrowed from the caller.
+#[component]
+#[allow(clippy::needless_lifetimes)]
+pub fn label<'a, T>(
+    /// The text of the label
+    text: &'a str,
+    /// Shown after the text
+    suffix: &'a T,
+) -> Dom
+where
+    T: Display,
+{
+    rsx! { <span>{format!("{text}{suffix}")}</span> }
+}
+
+#[cfg(any())]
+#[component]
+fn never_compiled() -> Dom {
+    this_does_not_exist()
+}
+
+fn lists() -> Dom {
+    let text = String::from("Total: ");
+    rsx! {
+        <div>
+            <List items={vec![1, 2, 3]} />
+            <List items={vec!["a", "b"]} />
+            <Label text={text.as_str()} suffix={&3} />
+        </div>
+    }
+}
+
+fn main() {}
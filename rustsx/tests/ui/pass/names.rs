@@ -0,0 +1,12 @@
+#![allow(dead_code)]
+use rustsx::{dominator::Dom, prelude::*};
+
+fn names() -> Dom {
+    rsx! {
+        <my-widget data-id="1" aria-label="Widget" type="button">
+            <svg><use xlink:href="#icon" /></svg>
+        </my-widget>
+    }
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+#![allow(dead_code)]
+use rustsx::{dominator::Dom, prelude::*};
+
+fn refs() -> Dom {
+    let input = NodeRef::<web_sys::HtmlInputElement>::new();
+    let element = NodeRef::<web_sys::HtmlElement>::new();
+    let node = NodeRef::<web_sys::Element>::new();
+    let canvas = NodeRef::<web_sys::HtmlCanvasElement>::default();
+    let circle = NodeRef::<web_sys::SvgCircleElement>::new();
+    rsx! {
+        <div ref={element}>
+            <input ref={input} />
+            <p ref={node}>{input.signal().map(|input| input.is_some().to_string())}</p>
+            <canvas ref={canvas} width={300} />
+            <svg><circle ref={circle} r="4" /></svg>
+            <button onclick={|_| if let Some(el) = input.get() { el.set_value(""); }}>"Clear"</button>
+        </div>
+    }
+}
+
+fn main() {}
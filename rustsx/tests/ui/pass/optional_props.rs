@@ -0,0 +1,29 @@
+#![allow(dead_code)]
+use rustsx::{dominator::Dom, prelude::*};
+
+#[component]
+fn heading(
+    title: String,
+    subtitle: Option<String>,
+    #[prop(default = 1)] level: u8,
+    #[prop(default)] classes: Vec<String>,
+) -> Dom {
+    rsx! {
+        <hgroup class={classes.join(" ")} data-level={level}>
+            <h1>{title}</h1>
+            <p>{subtitle.unwrap_or_default()}</p>
+        </hgroup>
+    }
+}
+
+fn headings() -> Dom {
+    rsx! {
+        <div>
+            <Heading title="Only required" />
+            <Heading title="All" subtitle="Sub" level={2} classes={vec!["a".to_string()]} />
+            <Heading level={3} title="Any order" />
+        </div>
+    }
+}
+
+fn main() {}
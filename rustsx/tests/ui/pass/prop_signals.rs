@@ -0,0 +1,32 @@
+#![allow(dead_code)]
+use rustsx::{
+    dominator::Dom,
+    futures_signals::signal::{Mutable, ReadOnlyMutable, SignalExt},
+    prelude::*,
+};
+
+#[component]
+fn counter(title: MaybeSignal<String>, count: Mutable<u32>, total: ReadOnlyMutable<u32>, label: String) -> Dom {
+    rsx! {
+        <p title={title.signal_cloned()}>
+            {label}": "{count.signal()}" of "{total.signal()}
+        </p>
+    }
+}
+
+fn counters() -> Dom {
+    let count = Mutable::new(1);
+    let total = Mutable::new(10);
+    let title = Mutable::new(String::from("Title"));
+    rsx! {
+        <div>
+            <Counter title="Literal" count={2} total={total.clone()} label="Plain" />
+            <Counter title={String::from("Owned")} count={count.clone()} total={total.read_only()} label={String::from("Owned")} />
+            <Counter title={title.clone()} count={count.clone()} total={total.clone()} label="Mutable" />
+            <Counter title={title.read_only()} count={count.clone()} total={total.clone()} label="Read-only" />
+            <Counter title={count.signal().map(|count| format!("{count} clicks"))} count={count} total={total} label="Signal" />
+        </div>
+    }
+}
+
+fn main() {}
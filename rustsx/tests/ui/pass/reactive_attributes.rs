@@ -0,0 +1,21 @@
+#![allow(dead_code)]
+use rustsx::{
+    dominator::Dom,
+    futures_signals::signal::{Mutable, SignalExt},
+    prelude::*,
+};
+
+fn link(class: Mutable<String>, href: Mutable<Option<String>>, count: Mutable<u32>) -> Dom {
+    let title: Option<&str> = None;
+    rsx! {
+        <div>
+            <a class={class.clone()} href={href.clone()} title={title}>"Link"</a>
+            <a href={href.read_only()} target={Some("_blank")}>"Read-only"</a>
+            <a href={href.signal_cloned().map(|href| href.map(|href| format!("{href}#top")))}>"Signal"</a>
+            <span data-count={count.clone()} title={count.signal().map(|count| format!("{count} items"))}>"Count"</span>
+            <td colspan={count.signal()} rowspan={Some(2)}>"Cell"</td>
+        </div>
+    }
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+// dominator's `html!` takes a level of macro recursion per method, so with a limit of 32 a `.child(..)` per child
+// fails past about 20 children. Static children are added with one `.children([..])` call, so these 32 fit.
+#![recursion_limit = "32"]
+#![allow(dead_code)]
+use rustsx::{dominator::Dom, prelude::*};
+
+fn long_list() -> Dom {
+    rsx! {
+        <ul>
+            <li>"0"</li> <li>"1"</li> <li>"2"</li> <li>"3"</li> <li>"4"</li> <li>"5"</li> <li>"6"</li> <li>"7"</li>
+            <li>"8"</li> <li>"9"</li> <li>"10"</li> <li>"11"</li> <li>"12"</li> <li>"13"</li> <li>"14"</li> <li>"15"</li>
+            <li>"16"</li> <li>"17"</li> <li>"18"</li> <li>"19"</li> <li>"20"</li> <li>"21"</li> <li>"22"</li> <li>"23"</li>
+            <li>"24"</li> <li>"25"</li> <li>"26"</li> <li>"27"</li> <li>"28"</li> <li>"29"</li> <li>"30"</li> <li>"31"</li>
+        </ul>
+    }
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+#![allow(dead_code)]
+use {
+    rustsx::{dominator::Dom, prelude::*},
+    std::collections::HashMap,
+};
+
+#[component]
+fn button(label: String, kind: Option<String>) -> Dom {
+    rsx! { <button type={kind.unwrap_or_default()}>{label}</button> }
+}
+
+fn elements(attrs: Vec<(&'static str, String)>, map: HashMap<String, String>) -> Dom {
+    rsx! {
+        <div {..attrs} id="explicit">
+            <span {..map} />
+        </div>
+    }
+}
+
+fn components(props: ButtonProps) -> Dom {
+    rsx! { <Button {..props} label="Overridden" /> }
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+use rustsx::{
+    dominator::Dom,
+    futures_signals::signal::{Mutable, SignalExt},
+    prelude::*,
+};
+
+fn styled(color: Mutable<String>, hidden: Mutable<bool>, width: Mutable<Option<String>>) -> Dom {
+    let size = "12px";
+    let weight: Option<&str> = None;
+    rsx! {
+        <div>
+            <p style={{
+                color: color,
+                "margin-top": "4px" !important,
+                font-size: size,
+                font-weight: weight,
+                width: width.read_only(),
+                display: hidden.signal().map(|hidden| hidden.then_some("none")),
+            }}>"Styled"</p>
+            <p style={{}}>"Empty"</p>
+            <p style="color: red">"String"</p>
+            <p style={{ let style = "color: blue"; style }}>"Block"</p>
+        </div>
+    }
+}
+
+fn main() {}
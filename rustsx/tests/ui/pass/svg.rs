@@ -0,0 +1,30 @@
+#![allow(dead_code)]
+use rustsx::{dominator::Dom, futures_signals::signal::Mutable, prelude::*};
+
+fn icon(radius: Mutable<String>, label: &str) -> Dom {
+    rsx! {
+        <svg viewBox="0 0 24 24" width="24" aria-label={label} onclick={|_, svg| { let _: f32 = svg.current_scale(); }}>
+            <defs>
+                <linearGradient id="fill" gradientUnits="userSpaceOnUse">
+                    <stop offset="0" stop-color="red" />
+                </linearGradient>
+                <filter id="blur"><feGaussianBlur stdDeviation="2" /></filter>
+            </defs>
+            <circle cx="12" cy="12" r={radius.clone()} fill="url(#fill)" style="opacity: 0.5" />
+            <a href="/home"><text x="0" y="20">"Home"<tspan>"!"</tspan></text></a>
+            <use xlink:href="#fill" />
+            <g onclick={|_, g: web_sys::SvggElement| g.remove()}><title>"Group"</title></g>
+            <foreignObject width="24" height="24">
+                <p style={{ color: "red" }}>"HTML again"</p>
+                <input type="text" disabled />
+            </foreignObject>
+        </svg>
+    }
+}
+
+// Tags that only exist in SVG can be rendered on their own, to be mounted into an `<svg>` elsewhere
+fn dot() -> Dom {
+    rsx! { <circle r="1" onclick={|_, circle: web_sys::SvgCircleElement| circle.remove()} /> }
+}
+
+fn main() {}
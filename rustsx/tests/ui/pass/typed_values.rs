@@ -0,0 +1,33 @@
+#![allow(dead_code)]
+use rustsx::{
+    dominator::Dom,
+    futures_signals::signal::{Mutable, SignalExt},
+    prelude::*,
+    web_sys::Url,
+};
+
+fn numbers(span: Mutable<u8>, max: Mutable<Option<f64>>, limit: Option<usize>) -> Dom {
+    rsx! {
+        <form method="POST" target="results" action="/search">
+            <input type="Email" maxlength={limit} width={32_i64} tabindex={-1} />
+            <td colspan={span.clone()} rowspan={span.signal().map(|span| span * 2)}>"Cell"</td>
+            <meter value={0.5} max={max.clone()} optimum={1.0_f32} />
+            <progress value={3} max={10_u128} />
+            <textarea wrap="hard" minlength={2}></textarea>
+        </form>
+    }
+}
+
+fn urls(href: String, src: Mutable<Option<String>>, poster: &Url, base: Url) -> Dom {
+    rsx! {
+        <div>
+            <a href={&href} target="_blank">"Link"</a>
+            <a href={href.clone()} target="_TOP">"Top"</a>
+            <img src={src.clone()} alt={format!("{href} image")} />
+            <video poster={poster} src={Some(base)} preload="" />
+            <iframe src={src.signal_cloned()} loading="lazy" />
+        </div>
+    }
+}
+
+fn main() {}